@@ -5,7 +5,6 @@ use twilight_gateway::{stream::{self, ShardEventStream}, Config};
 use twilight_http::Client;
 use twilight_model::gateway::event::Event;
 use twilight_model::gateway::Intents;
-use twilight_model::http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType};
 use twilight_model::id::Id;
 use vesper::prelude::*;
 
@@ -54,17 +53,7 @@ async fn main() {
 #[command]
 #[description = "Says hello"]
 async fn hello(ctx: &SlashContext<()>) -> DefaultCommandResult {
-    ctx.interaction_client.create_response(
-        ctx.interaction.id,
-        &ctx.interaction.token,
-            &InteractionResponse {
-            kind: InteractionResponseType::ChannelMessageWithSource,
-            data: Some(InteractionResponseData {
-                content: Some(String::from("Hello!")),
-                ..Default::default()
-            })
-        }
-    ).await?;
+    ctx.reply().content("Hello!").await?;
 
     Ok(())
 }
@@ -99,7 +99,7 @@ async fn increment_one(ctx: &SlashContext<Shared>) -> DefaultCommandResult {
         &InteractionResponse {
             kind: InteractionResponseType::ChannelMessageWithSource,
             data: Some(InteractionResponseData {
-                content: Some(format!("The count number has been incremented by one")),
+                content: Some(String::from("The count number has been incremented by one")),
                 ..Default::default()
             })
         }
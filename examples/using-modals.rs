@@ -55,11 +55,12 @@ async fn main() {
 async fn show_modal(ctx: &SlashContext<()>) -> DefaultCommandResult {
     let wait = ctx.create_modal::<MyModal>().await?;
     let output = wait.await?;
-    println!("{output:?}");
+    println!("{} {:?}", output.something, output.field_2);
     Ok(())
 }
 
 #[derive(Modal, Debug)]
+#[modal(title = "My modal")]
 struct MyModal {
     #[modal(placeholder = "My placeholder")]
     something: String,
@@ -0,0 +1,140 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse2, spanned::Spanned, Data, DeriveInput, Error, Fields, Result, Type};
+use crate::command::argument::Argument;
+use crate::util;
+
+#[derive(FromAttributes, Default)]
+#[darling(attributes(arguments))]
+#[darling(default)]
+struct ArgumentsOptions {
+    /// The framework data type the arguments are implemented for, required when autocomplete
+    /// functions are used, as those are only available for a concrete data type.
+    data: Option<Type>,
+}
+
+pub fn arguments(input: TokenStream2) -> Result<TokenStream2> {
+    let derive = parse2::<DeriveInput>(input)?;
+    let fields = match &derive.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(
+                derive.ident.span(),
+                "This derive is only available for structs with named fields"
+            ))
+        },
+        _ => return Err(Error::new(
+            derive.ident.span(),
+            "This derive is only available for structs"
+        ))
+    };
+
+    if !derive.generics.params.is_empty() {
+        return Err(Error::new(
+            derive.generics.span(),
+            "This derive is not available for generic structs"
+        ));
+    }
+
+    let options = ArgumentsOptions::from_attributes(derive.attrs.as_slice())?;
+
+    let mut arguments = Vec::new();
+    for field in fields {
+        let mut attrs = field.attrs.clone();
+        attrs.retain(|attribute| !attribute.path().is_ident("doc"));
+        let argument = Argument::from_parts(
+            field.ident.clone().unwrap(),
+            Box::new(field.ty.clone()),
+            &mut attrs,
+            field.span(),
+            true
+        )?;
+
+        if argument.injected {
+            return Err(Error::new(
+                field.span(),
+                "Context extractors can only be used as command parameters"
+            ));
+        }
+
+        if argument.has_autocomplete() && options.data.is_none() {
+            return Err(Error::new(
+                field.span(),
+                "Autocomplete requires the data type to be set with `#[arguments(data = Type)]`"
+            ));
+        }
+
+        arguments.push(argument);
+    }
+
+    Argument::validate_options(&arguments)?;
+
+    let (data, generics) = match &options.data {
+        Some(data) => (quote::quote!(#data), None),
+        None => (quote::quote!(__D), Some(quote::quote!(<__D: Send + Sync + 'static>)))
+    };
+
+    let iterator = quote::format_ident!("iterator");
+    let registrations = arguments.iter().map(|argument| {
+        let ty = &argument.ty;
+        if argument.is_flatten() {
+            quote::quote! {
+                arguments.extend(<#ty as CommandArguments<#data>>::arguments::<__T, __E>());
+            }
+        } else {
+            let builders = argument.builders();
+            quote::quote!(#(arguments.push(#builders);)*)
+        }
+    });
+    let bounds = arguments.iter().map(|argument| {
+        let ty = &argument.ty;
+        if argument.is_flatten() {
+            return quote::quote!(#ty: CommandArguments<#data>);
+        }
+
+        // Variadic arguments parse each one of their items instead of the whole vector.
+        match argument.attributes.as_ref().and_then(|a| a.variadic.as_ref()) {
+            Some(_) => {
+                let inner = util::get_vec_inner(ty);
+                quote::quote!(#inner: ::vesper::parse::Parse<#data>)
+            },
+            None => quote::quote!(#ty: ::vesper::parse::Parse<#data>)
+        }
+    });
+    let (names, parsers) = (
+        arguments.iter().map(|a| &a.ident).collect::<Vec<_>>(),
+        arguments.iter().map(|a| a.parse_expr(&iterator)).collect::<Vec<_>>()
+    );
+
+    let struct_name = &derive.ident;
+
+    Ok(quote::quote! {
+        const _: () = {
+            use ::vesper::{
+                argument::{CommandArgument, CommandArguments},
+                iter::DataIterator,
+                parse::ParseError,
+                prelude::async_trait,
+            };
+
+            #[automatically_derived]
+            #[async_trait]
+            impl #generics CommandArguments<#data> for #struct_name
+            where
+                #(#bounds),*
+            {
+                fn arguments<__T, __E>() -> Vec<CommandArgument<#data, __T, __E>> {
+                    let mut arguments = Vec::new();
+                    #(#registrations)*
+                    arguments
+                }
+
+                async fn parse(#iterator: &mut DataIterator<'_, #data>) -> Result<Self, ParseError> {
+                    #(let #names = #parsers.await?;)*
+
+                    Ok(Self { #(#names),* })
+                }
+            }
+        };
+    })
+}
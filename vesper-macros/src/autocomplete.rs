@@ -1,6 +1,6 @@
 use proc_macro2::{TokenStream as TokenStream2};
 use syn::{
-    parse2, spanned::Spanned, Error, ItemFn, Result
+    parse2, spanned::Spanned, Error, ItemFn, Result, ReturnType
 };
 use crate::util;
 
@@ -15,6 +15,21 @@ pub fn autocomplete(input: TokenStream2) -> Result<TokenStream2> {
     }
 
     let data_type = util::get_context_type(&fun.sig, false)?;
+
+    // Convert the returned value into the response sent to discord.
+    let output = match &fun.sig.output {
+        ReturnType::Default => quote::quote!(()),
+        ReturnType::Type(_, ty) => quote::quote!(#ty),
+    };
+    let block = &fun.block;
+    fun.block = parse2(quote::quote!({
+        let response: #output = async move #block.await;
+        ::vesper::autocomplete::IntoAutocompleteResponse::into_response(response)
+    }))?;
+    fun.sig.output = parse2(quote::quote!(
+        -> Option<::vesper::twilight_exports::InteractionResponseData>
+    ))?;
+
     util::set_context_lifetime(&mut fun.sig)?;
     let hook = util::get_hook_macro();
     let path = quote::quote!(::vesper::hook::AutocompleteHook);
@@ -1,14 +1,42 @@
 use crate::extractors::{Either, FixedList, FunctionPath, Map};
 use crate::optional::Optional;
-use crate::util;
+use crate::{util, validate};
 use darling::FromMeta;
 use darling::export::NestedMeta;
-use proc_macro2::{Ident, TokenStream};
+use darling::util::PathList;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 use syn::spanned::Spanned;
-use syn::{FnArg, Type, LitStr, Error};
+use syn::{Attribute, Expr, FnArg, Type, LitStr, Error};
 use crate::extractors::function_closure::FunctionOrClosure;
 
+/// The value used when an argument is not provided, set with `#[default]` to use the type's
+/// `Default` implementation, or with `#[default = expr]` to use the given expression.
+pub enum DefaultValue {
+    Trait,
+    Expr(Expr),
+}
+
+impl FromMeta for DefaultValue {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Trait)
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(Self::Expr(expr.clone()))
+    }
+}
+
+impl DefaultValue {
+    /// Gets the expression used to create the default value.
+    pub fn expr(&self) -> TokenStream {
+        match self {
+            Self::Trait => quote::quote!(::std::default::Default::default()),
+            Self::Expr(expr) => quote::quote!(#expr),
+        }
+    }
+}
+
 #[derive(FromMeta)]
 pub struct ArgumentAttributes {
     #[darling(default)]
@@ -37,8 +65,45 @@ pub struct ArgumentAttributes {
     #[darling(rename = "rename")]
     pub renaming: Option<Either<String, FixedList<1, String>>>,
     pub autocomplete: Optional<Either<FunctionPath, FixedList<1, FunctionPath>>>,
+    /// A function returning the choices of this argument, e.g.: #[choices_fn = game_modes]
+    #[darling(default)]
+    pub choices_fn: Option<Either<FunctionOrClosure, FixedList<1, FunctionOrClosure>>>,
+    #[darling(default)]
+    pub skip: bool,
+    /// The minimum value of a numeric argument, e.g.: #[min = 1]
+    #[darling(default)]
+    pub min: Option<Expr>,
+    /// The maximum value of a numeric argument, e.g.: #[max = 100]
+    #[darling(default)]
+    pub max: Option<Expr>,
+    /// The minimum length of a string argument, e.g.: #[min_length = 1]
+    #[darling(default)]
+    pub min_length: Option<Expr>,
+    /// The maximum length of a string argument, e.g.: #[max_length = 100]
+    #[darling(default)]
+    pub max_length: Option<Expr>,
+    /// The channel types allowed in a channel argument, e.g.: #[channel_types(GuildText)]
     #[darling(default)]
-    pub skip: bool
+    pub channel_types: Option<PathList>,
+    /// The value used when the argument is not provided, e.g.: #[default = 10]
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+    /// Expands a `Vec<T>` argument into multiple options, e.g.: #[variadic(min = 1, max = 10)]
+    #[darling(default)]
+    pub variadic: Option<Variadic>,
+    /// Expands a type implementing `CommandArguments` into its options, e.g.: #[flatten]
+    #[darling(default)]
+    pub flatten: bool
+}
+
+/// The amount of options a variadic argument is expanded into.
+#[derive(FromMeta)]
+pub struct Variadic {
+    /// The amount of required options.
+    #[darling(default)]
+    pub min: usize,
+    /// The total amount of options.
+    pub max: usize,
 }
 
 /// A command argument, and all its details, skipping the first one, which must be an `SlashContext`
@@ -54,7 +119,12 @@ pub struct Argument {
     pub ty: Box<Type>,
     /// Argument attributes, only present if the command is a `chat` command.
     pub attributes: Option<ArgumentAttributes>,
-    pub chat_command: bool
+    pub chat_command: bool,
+    /// Whether this argument is resolved from the context instead of being a command option,
+    /// either because it is marked with `#[inject]` or because it is a known context extractor.
+    pub injected: bool,
+    /// The span of the argument, used to report errors.
+    pub span: Span
 }
 
 impl Argument {
@@ -63,11 +133,49 @@ impl Argument {
         mut arg: FnArg,
         chat_command: bool
     ) -> darling::Result<Self> {
+        let span = arg.span();
         let pat = util::get_pat_mut(&mut arg)?;
         let ident = util::get_ident(&pat.pat)?;
-        let ty = pat.ty.clone();
 
-        let attributes = pat.attrs
+        Self::from_parts(ident, pat.ty.clone(), &mut pat.attrs, span, chat_command)
+    }
+
+    /// Creates a new [argument](self::Argument) from the given name, type and attributes, which
+    /// can come either from a function parameter or from a struct field.
+    pub fn from_parts(
+        ident: Ident,
+        ty: Box<Type>,
+        attrs: &mut Vec<Attribute>,
+        span: Span,
+        chat_command: bool
+    ) -> darling::Result<Self> {
+        let mut injected = util::is_context_extractor(&ty);
+        attrs.retain(|attribute| {
+            if attribute.path().is_ident("inject") {
+                injected = true;
+                false
+            } else {
+                true
+            }
+        });
+
+        if injected {
+            return Ok(Self {
+                ident,
+                ty,
+                attributes: None,
+                chat_command,
+                injected,
+                span
+            });
+        }
+
+        let attribute_span = |name: &str| attrs.iter()
+            .find(|attribute| attribute.path().is_ident(name))
+            .map(|attribute| attribute.span());
+        let (rename_span, description_span) = (attribute_span("rename"), attribute_span("description"));
+
+        let attributes = attrs
             .drain(..)
             .map(|attribute| attribute.meta)
             .map(NestedMeta::Meta)
@@ -81,28 +189,250 @@ impl Argument {
             } else {
                 None
             },
-            chat_command
+            chat_command,
+            injected,
+            span
         };
 
+        if let Some(variadic) = this.attributes.as_ref().and_then(|a| a.variadic.as_ref()) {
+            let attributes = this.attributes.as_ref().unwrap();
+            let error = if util::get_vec_inner(&this.ty).is_none() {
+                Some("Variadic arguments must be a `Vec<T>`")
+            } else if variadic.max == 0 || variadic.max > 25 || variadic.min > variadic.max {
+                Some("Variadic arguments must satisfy `min <= max` and `0 < max <= 25`")
+            } else if attributes.default.is_some() {
+                Some("Variadic arguments can't have a default value")
+            } else if attributes.localized_names_fn.is_some() {
+                Some("Variadic arguments can't use `localized_names_fn`")
+            } else {
+                None
+            };
+
+            if let Some(error) = error {
+                return Err(Error::new(span, error).into());
+            }
+        }
+
+        if let Some(attributes) = this.attributes.as_ref() {
+            if attributes.choices_fn.is_some() && attributes.autocomplete.is_some() {
+                return Err(Error::new(
+                    span,
+                    "Arguments can't have both `choices_fn` and `autocomplete`"
+                ).into());
+            }
+        }
+
+        if this.is_flatten() {
+            let attributes = this.attributes.as_ref().unwrap();
+            if attributes.description.is_some()
+                || attributes.renaming.is_some()
+                || attributes.autocomplete.is_some()
+                || attributes.choices_fn.is_some()
+                || attributes.default.is_some()
+                || attributes.variadic.is_some()
+                || attributes.skip
+                || !this.constraints().is_empty()
+            {
+                return Err(Error::new(
+                    span,
+                    "Flattened arguments can't have other argument attributes"
+                ).into());
+            }
+
+            return Ok(this);
+        }
+
         if chat_command 
             && !this.attributes.as_ref().map(|a| a.skip).unwrap_or(false)
             && this.attributes.as_ref().unwrap()
                 .description.as_ref().map(|d| d.inner().is_empty()).unwrap_or(true) 
         {
             return Err(Error::new(
-                arg.span(),
+                span,
                 "Missing `description`"
-            )).map_err(From::from);
+            ).into());
+        }
+
+        if let Some(attributes) = this.attributes.as_ref().filter(|a| !a.skip) {
+            this.validate(attributes, rename_span.unwrap_or(this.ident.span()), description_span)?;
         }
 
         Ok(this)
     }
+
+    /// Checks the names and descriptions of the argument are accepted by discord.
+    fn validate(
+        &self,
+        attributes: &ArgumentAttributes,
+        name_span: Span,
+        description_span: Option<Span>
+    ) -> syn::Result<()> {
+        let name = self.name();
+        validate::chat_name(&name, "Argument name", name_span)?;
+
+        if let Some(variadic) = &attributes.variadic {
+            validate::chat_name(&format!("{}{}", name, variadic.max), "Argument name", name_span)?;
+        }
+
+        if let Some(description) = &attributes.description {
+            validate::description(description.inner(), description_span.unwrap_or(self.span))?;
+        }
+
+        if let Some(localized_names) = &attributes.localized_names {
+            validate::localized_names(localized_names, true)?;
+        }
+
+        if let Some(localized_descriptions) = &attributes.localized_descriptions {
+            validate::localized_descriptions(localized_descriptions)?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets whether each one of the options registered by this argument is required, which is
+    /// unknown for flattened arguments.
+    fn required_options(&self) -> Vec<bool> {
+        let Some(attributes) = &self.attributes else {
+            return Vec::new();
+        };
+
+        if self.injected || attributes.skip || attributes.flatten {
+            return Vec::new();
+        }
+
+        if let Some(variadic) = &attributes.variadic {
+            return (1..=variadic.max).map(|index| index <= variadic.min).collect();
+        }
+
+        vec![attributes.default.is_none() && !util::is_option(&self.ty)]
+    }
+
+    /// Checks the options registered by the given arguments don't exceed the amount allowed by
+    /// discord, and that required options are not placed after optional ones.
+    pub fn validate_options(arguments: &[Argument]) -> syn::Result<()> {
+        let mut count = 0;
+        let mut optional_found = false;
+
+        for argument in arguments {
+            let options = argument.required_options();
+            count += options.len();
+
+            if count > validate::OPTIONS_MAX {
+                return Err(Error::new(
+                    argument.span,
+                    format!("Commands can't have more than {} options", validate::OPTIONS_MAX)
+                ));
+            }
+
+            for required in options {
+                if required && optional_found {
+                    return Err(Error::new(
+                        argument.span,
+                        "Required arguments can't be placed after optional ones"
+                    ));
+                }
+
+                optional_found |= !required;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether this argument is a group of arguments expanded with `#[flatten]`.
+    pub fn is_flatten(&self) -> bool {
+        self.attributes.as_ref().map(|a| a.flatten).unwrap_or(false)
+    }
+
+    /// Whether this argument uses an autocomplete function.
+    pub fn has_autocomplete(&self) -> bool {
+        self.attributes.as_ref().map(|a| a.autocomplete.is_some()).unwrap_or(false)
+    }
 }
 
-impl ToTokens for Argument {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        if self.attributes.as_ref().map(|a| a.skip).unwrap_or(false) || !self.chat_command {
-            return;
+impl Argument {
+    /// Gets the expression parsing this argument from the `DataIterator` with the given name.
+    pub fn parse_expr(&self, iterator: &Ident) -> TokenStream {
+        let ty = &self.ty;
+        let name = self.name();
+        let constraints = self.constraints();
+
+        if self.is_flatten() {
+            return quote::quote! {
+                <#ty as ::vesper::argument::CommandArguments<_>>::parse(#iterator)
+            };
+        }
+
+        if let Some(variadic) = self.attributes.as_ref().and_then(|a| a.variadic.as_ref()) {
+            let inner = util::get_vec_inner(ty);
+            let (min, max) = (variadic.min, variadic.max);
+            return quote::quote! {
+                #iterator.variadic_parse::<#inner>(#name, #min, #max, &[#(#constraints),*])
+            };
+        }
+
+        match self.attributes.as_ref().and_then(|a| a.default.as_ref()) {
+            Some(default) => {
+                let default = default.expr();
+                quote::quote!(#iterator.parse_or_else::<#ty, _>(#name, &[#(#constraints),*], || #default))
+            },
+            None => quote::quote!(#iterator.constrained_parse::<#ty>(#name, &[#(#constraints),*]))
+        }
+    }
+
+    /// Gets the name this argument has in discord.
+    pub fn name(&self) -> String {
+        match self.attributes.as_ref().and_then(|a| a.renaming.as_ref()) {
+            Some(rename) => rename.inner().clone(),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// Gets the constraints of this argument as `ArgumentConstraint` expressions.
+    pub fn constraints(&self) -> Vec<TokenStream> {
+        let Some(attributes) = &self.attributes else {
+            return Vec::new();
+        };
+        let path = quote::quote!(::vesper::argument::ArgumentConstraint);
+        let mut constraints = Vec::new();
+
+        if let Some(min) = &attributes.min {
+            constraints.push(quote::quote!(#path::MinValue((#min) as f64)));
+        }
+
+        if let Some(max) = &attributes.max {
+            constraints.push(quote::quote!(#path::MaxValue((#max) as f64)));
+        }
+
+        if let Some(min) = &attributes.min_length {
+            constraints.push(quote::quote!(#path::MinLength(#min)));
+        }
+
+        if let Some(max) = &attributes.max_length {
+            constraints.push(quote::quote!(#path::MaxLength(#max)));
+        }
+
+        if let Some(types) = &attributes.channel_types {
+            let types = types.iter();
+            constraints.push(quote::quote!(#path::ChannelTypes(vec![
+                #(::vesper::twilight_exports::ChannelType::#types),*
+            ])));
+        }
+
+        constraints
+    }
+}
+
+impl Argument {
+    /// Gets the `CommandArgument` expressions registering this argument, which are more than one
+    /// for variadic arguments and none for injected, skipped and flattened ones.
+    pub fn builders(&self) -> Vec<TokenStream> {
+        if self.injected
+            || self.attributes.as_ref().map(|a| a.skip).unwrap_or(false)
+            || !self.chat_command
+            || self.is_flatten()
+        {
+            return Vec::new();
         }
         let attributes = self.attributes.as_ref().unwrap();
 
@@ -110,10 +440,7 @@ impl ToTokens for Argument {
         let ty = &self.ty;
         let argument_path = quote::quote!(::vesper::argument::CommandArgument);
 
-        let name = match &attributes.renaming {
-            Some(rename) => rename.inner().clone(),
-            None => self.ident.to_string(),
-        };
+        let name = self.name();
 
         let add_localized_names = attributes.localized_names.as_ref().map(|map| {
             let localized_names = map.pairs();
@@ -140,17 +467,69 @@ impl ToTokens for Argument {
             quote::quote!(#inner())
         });
 
-        tokens.extend(quote::quote! {
-            .add_argument(#argument_path::new::<#ty>(
-                #name,
-                #des,
-                #autocomplete
-            )
-            #add_localized_names
-            #add_localized_names_fn
-            #add_localized_descriptions
-            #add_localized_descriptions_fn
-            )   
+        let add_choices_fn = attributes.choices_fn.as_ref().map(|fun| {
+            let fun = fun.inner();
+            quote::quote!(.choices_fn(#fun))
         });
+
+        let constraints = self.constraints();
+
+        // The default value is shown in the description when its type implements `Display`.
+        let default = attributes.default.as_ref().map(|default| {
+            let expr = default.expr();
+            quote::quote!(.default_display({
+                use ::vesper::extract::{DisplayDefault as _, NoDisplayDefault as _};
+                let value: #ty = #expr;
+                (&::vesper::extract::DefaultDisplay(&value)).display_default()
+            }))
+        });
+
+        // Variadic arguments are expanded into `name1..nameN`, being the first `min` required.
+        let arguments = match (&attributes.variadic, util::get_vec_inner(ty)) {
+            (Some(variadic), Some(inner)) => (1..=variadic.max)
+                .map(|index| {
+                    let index_lit = Literal::usize_unsuffixed(index);
+                    let localized_names = attributes.localized_names.as_ref().map(|map| {
+                        let pairs = map.iter().map(|(k, v)| quote::quote!((#k, concat!(#v, #index_lit))));
+                        quote::quote!(.localized_names(vec![#(#pairs),*]))
+                    });
+                    let optional = (index > variadic.min).then(|| quote::quote!(.with_default()));
+
+                    (format!("{}{}", name, index), inner, localized_names, optional)
+                })
+                .collect::<Vec<_>>(),
+            _ => vec![(name, &**ty, add_localized_names, default)]
+        };
+
+        arguments.into_iter()
+            .map(|(name, ty, add_localized_names, extra)| quote::quote! {
+                #argument_path::new::<#ty>(
+                    #name,
+                    #des,
+                    #autocomplete
+                )
+                #add_localized_names
+                #add_localized_names_fn
+                #add_localized_descriptions
+                #add_localized_descriptions_fn
+                #add_choices_fn
+                #(.constraint(#constraints))*
+                #extra
+            })
+            .collect()
+    }
+}
+
+impl ToTokens for Argument {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.is_flatten() {
+            let ty = &self.ty;
+            tokens.extend(quote::quote!(.add_arguments::<#ty>()));
+            return;
+        }
+
+        for builder in self.builders() {
+            tokens.extend(quote::quote!(.add_argument(#builder)));
+        }
     }
 }
@@ -1,5 +1,5 @@
 use darling::{FromMeta, export::NestedMeta};
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{Token, Meta, parse2, Error, LitStr};
@@ -9,6 +9,7 @@ use syn::punctuated::Punctuated;
 
 use crate::extractors::{Either, FixedList, FunctionPath, Ident, List, Map};
 use crate::extractors::function_closure::FunctionOrClosure;
+use crate::validate;
 
 #[derive(Default, FromMeta)]
 /// The details of a given command
@@ -34,11 +35,43 @@ pub struct CommandDetails {
     #[darling(default)]
     pub nsfw: bool,
     #[darling(default)]
-    pub only_guilds: bool
+    pub only_guilds: bool,
+    #[darling(default)]
+    pub defer: Option<Defer>
+}
+
+/// The `#[defer]` attribute, which can be used as `#[defer]` or `#[defer(ephemeral)]`.
+#[derive(Default)]
+pub struct Defer {
+    pub ephemeral: bool
+}
+
+#[derive(FromMeta)]
+struct DeferOptions {
+    #[darling(default)]
+    ephemeral: bool
+}
+
+impl FromMeta for Defer {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let options = DeferOptions::from_list(items)?;
+        Ok(Self {
+            ephemeral: options.ephemeral
+        })
+    }
 }
 
 impl CommandDetails {
     pub fn parse(input_options: InputOptions, attrs: &mut Vec<Attribute>) -> Result<Self> {
+        let description_span = attrs.iter()
+            .find(|attribute| attribute.path().is_ident("description"))
+            .map(|attribute| attribute.span())
+            .unwrap_or_else(Span::call_site);
+
         let meta = attrs
             .drain(..)
             .map(|item| item.meta)
@@ -47,6 +80,18 @@ impl CommandDetails {
 
         let mut this = Self::from_list(meta.as_slice())?;
 
+        if input_options.chat {
+            validate::description(this.description.inner(), description_span)?;
+        }
+
+        if let Some(localized_names) = &this.localized_names {
+            validate::localized_names(localized_names, input_options.chat)?;
+        }
+
+        if let Some(localized_descriptions) = &this.localized_descriptions {
+            validate::localized_descriptions(localized_descriptions)?;
+        }
+
         this.input_options = input_options;
         Ok(this)
     }
@@ -120,6 +165,11 @@ impl ToTokens for CommandDetails {
             .nsfw(#nsfw)
             .only_guilds(#only_guilds)
         ));
+
+        if let Some(defer) = &self.defer {
+            let ephemeral = defer.ephemeral;
+            tokens.extend(quote::quote!(.defer(#ephemeral)));
+        }
     }
 }
 
@@ -1,10 +1,10 @@
-mod argument;
+pub mod argument;
 mod details;
 
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use syn::{parse2, spanned::Spanned, Block, Error, ItemFn, Result, Signature, Type};
 use {argument::Argument, details::CommandDetails};
-use crate::util;
+use crate::{util, validate};
 
 use self::details::InputOptions;
 
@@ -29,7 +29,13 @@ pub fn command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenSt
         ));
     }
 
+    let name_span = if macro_attrs.is_empty() {
+        sig.ident.span()
+    } else {
+        macro_attrs.span()
+    };
     let input_options = InputOptions::new(macro_attrs, &sig.ident)?;
+    validate::command_name(&input_options.name, input_options.chat, name_span)?;
 
     // The name of the function
     let ident = sig.ident.clone();
@@ -83,37 +89,43 @@ pub fn parse_arguments(
 
     arguments.reverse();
 
-    let (names, types, renames) = (
-        arguments.iter().map(|s| &s.ident).collect::<Vec<_>>(),
-        arguments.iter().map(|s| &s.ty).collect::<Vec<_>>(),
-        arguments
-            .iter()
-            .map(|s| {
-                if let Some(renaming) = s.attributes.as_ref().map(|a| a.renaming.as_ref()).flatten() {
-                    renaming.inner().clone()
-                } else {
-                    s.ident.to_string()
-                }
-            })
-            .collect::<Vec<_>>(),
+    if chat_command {
+        Argument::validate_options(&arguments)?;
+    }
+
+    let (injected, options) = arguments.iter()
+        .partition::<Vec<_>, _>(|arg| arg.injected);
+
+    let (injected_names, injected_types) = (
+        injected.iter().map(|s| &s.ident).collect::<Vec<_>>(),
+        injected.iter().map(|s| &s.ty).collect::<Vec<_>>(),
+    );
+
+    let iterator = quote::format_ident!("__options");
+    let (names, parsers) = (
+        options.iter().map(|s| &s.ident).collect::<Vec<_>>(),
+        options.iter().map(|s| s.parse_expr(&iterator)).collect::<Vec<_>>(),
     );
 
-    if !names.is_empty() {
+    if !arguments.is_empty() {
         // The original block of the function
         let b = &block;
 
-        // Modify the block to parse arguments
+        // Modify the block to resolve injected values and parse arguments
         *block = parse2(quote::quote! {{
+            #(let #injected_names =
+                <#injected_types as ::vesper::inject::FromContext<'_, _>>::from_context(#ctx_ident)?;)*
+
             let (#(#names),*) = {
-                let mut __options = ::vesper::iter::DataIterator::new(#ctx_ident);
+                let #iterator = &mut ::vesper::iter::DataIterator::new(#ctx_ident);
 
-                #(let #names =
-                    __options.named_parse::<#types>(#renames).await?;)*
+                #(let #names = #parsers.await?;)*
 
-                if __options.len() > 0 {
-                    return Err(
-                        ::vesper::prelude::ParseError::StructureMismatch("Too many arguments received".to_string()).into()
-                    );
+                if #iterator.len() > 0 {
+                    let error = #iterator.context()
+                        .translate("vesper.parse.too_many_arguments", &[])
+                        .unwrap_or_else(|| "Too many arguments received".to_string());
+                    return Err(::vesper::prelude::ParseError::StructureMismatch(error).into());
                 }
 
                 (#(#names),*)
@@ -3,6 +3,7 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 
 mod after;
+mod arguments;
 mod autocomplete;
 mod before;
 mod check;
@@ -14,6 +15,7 @@ mod modal;
 mod optional;
 mod parse;
 mod util;
+mod validate;
 
 /// Converts an `async` function into a normal function returning a
 /// `Pin<Box<dyn Future<Output = _> + '_>>`
@@ -87,6 +89,131 @@ pub fn hook(_: TokenStream, input: TokenStream) -> TokenStream {
 /// To use a function pointer, the attribute accepts both `#[localized_{names/descriptions}_fn = myfn]` and
 /// `#[localized_{names/descriptions}_fn(myfn)]`
 ///
+/// ### Constraints:
+/// Arguments can be constrained using the `#[min = ..]` and `#[max = ..]` attributes for numbers,
+/// `#[min_length = ..]` and `#[max_length = ..]` for strings, and `#[channel_types(..)]` for
+/// channels, which accepts a list of twilight `ChannelType` variants. These constraints are
+/// applied on top of the ones set by the argument type and checked again when parsing the
+/// argument:
+/// ```
+/// #[command]
+/// #[description = "Purges messages"]
+/// async fn purge(
+///     ctx: &mut SlashContext</* Context type */>,
+///     #[description = "The amount of messages"] #[min = 1] #[max = 100] amount: i64,
+///     #[description = "The channel to purge"] #[channel_types(GuildText, GuildForum)] channel: Option<Id<ChannelMarker>>
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// ### Default values:
+/// Instead of using an `Option<T>` argument, the `#[default]` attribute can be used to make the
+/// argument optional, falling back to the `Default` implementation of the type if the argument
+/// is not provided. A value can also be specified with `#[default = expr]`. When the type of the
+/// argument implements `Display`, the default value is shown in the argument description seen in
+/// discord:
+/// ```
+/// #[command]
+/// #[description = "Rolls a dice"]
+/// async fn roll(
+///     ctx: &mut SlashContext</* Context type */>,
+///     #[description = "The number of faces"] #[default = 6] faces: u8,
+///     #[description = "Whether to hide the result"] #[default] hidden: bool
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// ### Variadic arguments:
+/// A `Vec<T>` argument marked with `#[variadic(min = .., max = ..)]` is expanded into `max`
+/// options named `name1..nameN`, the first `min` of them being required. The provided values are
+/// collected back in order:
+/// ```
+/// #[command]
+/// #[description = "Creates a poll"]
+/// async fn poll(
+///     ctx: &mut SlashContext</* Context type */>,
+///     #[description = "A poll option"] #[variadic(min = 2, max = 10)] option: Vec<String>
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// ### Injected values:
+/// Arguments whose type implements `FromContext` are resolved from the context instead of being
+/// registered as command options, so they don't need a `description`. Those arguments must be
+/// marked with the `#[inject]` attribute, unless their type is one of the `Author`, `GuildId`,
+/// `Locale` and `State` extractors:
+/// ```
+/// #[command]
+/// #[description = "Shows the user's settings"]
+/// async fn settings(
+///     ctx: &mut SlashContext<Data>,
+///     author: Author,
+///     guild_id: Option<vesper::inject::GuildId>,
+///     settings: State<Settings>,
+///     #[inject] custom: MyExtractor
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// ### Dynamic choices:
+/// The choices of an argument can be computed when the command is registered using the
+/// `#[choices_fn = function]` attribute, being the function a
+/// `fn(&Framework<D, T, E>) -> Vec<CommandOptionChoice>`. The function is called again when the
+/// argument is parsed, failing with a parse error if the received value is not one of the
+/// choices. Arguments with dynamic choices can't be autocompleted:
+/// ```
+/// fn game_modes(framework: &Framework<Data>) -> Vec<CommandOptionChoice> {
+///     framework.data.game_modes.iter()
+///         .map(|mode| CommandOptionChoice {
+///             name: mode.name.clone(),
+///             name_localizations: None,
+///             value: CommandOptionChoiceValue::String(mode.id.clone())
+///         })
+///         .collect()
+/// }
+///
+/// #[command]
+/// #[description = "Starts a game"]
+/// async fn play(
+///     ctx: &mut SlashContext<Data>,
+///     #[description = "The game mode to play"] #[choices_fn = game_modes] mode: String
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// ### Flattened arguments:
+/// Groups of arguments shared between commands can be declared once in a struct deriving
+/// `CommandArguments`, and then used in any command by marking the parameter with `#[flatten]`,
+/// which expands the struct into its options:
+/// ```
+/// #[derive(CommandArguments)]
+/// struct Moderation {
+///     #[description = "The user to moderate"]
+///     target: User,
+///     #[description = "The reason of the action"]
+///     reason: Option<String>,
+/// }
+///
+/// #[command]
+/// #[description = "Bans a user"]
+/// async fn ban(
+///     ctx: &mut SlashContext<()>,
+///     #[flatten] moderation: Moderation,
+///     #[description = "Days of messages to delete"] delete_days: Option<Range<u8, 0, 7>>
+/// ) -> DefaultCommandResult {
+///     // code here...
+/// }
+/// ```
+///
+/// As the options of a group are only known once the command is built, exceeding the amount of
+/// options allowed or placing a required option after an optional one of a group makes
+/// registering the command in the framework panic.
+///
 /// ## Specifying required permissions
 ///
 /// It is possible to specify the permissions needed to execute the command by using the
@@ -94,6 +221,17 @@ pub fn hook(_: TokenStream, input: TokenStream) -> TokenStream {
 /// [twilight permissions](https://docs.rs/twilight-model/latest/twilight_model/guild/struct.Permissions.html).
 /// For example, to specify that a user needs to have administrator permissions to execute a command,
 /// the attribute would be used like this `#[required_permissions(ADMINISTRATOR)]`.
+///
+/// ## Deferring the command
+///
+/// Commands that take a long time to respond can be deferred before executing by using the
+/// `#[defer]` attribute, the response can be made ephemeral by using `#[defer(ephemeral)]`.
+///
+/// ## Validation
+///
+/// Names, descriptions, localizations and the amount and order of the arguments are checked at
+/// compile time following the limits imposed by discord, so commands which would be rejected when
+/// registering them fail to compile instead.
 #[proc_macro_attribute]
 pub fn command(attrs: TokenStream, input: TokenStream) -> TokenStream {
     extract(command::command(attrs.into(), input.into()))
@@ -165,11 +303,73 @@ pub fn autocomplete(_: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// By default the variants are sent by discord as integers based on their position, so reordering
+/// them changes the values received. The kind of the values can be changed with
+/// `#[parse(kind = "string")]` or `#[parse(kind = "number")]`, and each variant can be given a
+/// fixed value with `#[parse(value = ...)]`. String values default to the variant name.
+///
+/// The names shown to the user can be localized using the
+/// `#[parse(localized_names("<LOCALE>" = "<NAME>"))]` attribute.
+///
+/// # Example:
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[derive(Parse)]
+/// #[parse(kind = "string")]
+/// enum Language {
+///     #[parse(value = "en", localized_names("es-ES" = "Inglés"))]
+///     English,
+///     #[parse(value = "es", localized_names("es-ES" = "Español"))]
+///     Spanish,
+/// }
+/// ```
+///
+/// As discord allows a maximum of 25 choices, enums with more variants are autocompleted
+/// instead, suggesting the variants matching the user input.
 #[proc_macro_derive(Parse, attributes(parse))]
 pub fn parse(input: TokenStream) -> TokenStream {
     extract(parse::parse(input.into()))
 }
 
+/// Implements the `CommandArguments` trait for the derived struct, allowing it to be used as a
+/// group of arguments in commands by marking the parameter with `#[flatten]`.
+///
+/// Fields accept the same attributes as `#[command]` parameters, including `#[flatten]` to nest
+/// other groups.
+///
+/// By default the trait is implemented for any framework data type. Groups using `#[autocomplete]`
+/// must specify the data type they are used with, as the autocomplete functions take a concrete
+/// one:
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[derive(CommandArguments)]
+/// #[arguments(data = MyData)]
+/// struct Search {
+///     #[description = "What to search"]
+///     #[autocomplete = search_autocomplete]
+///     query: String,
+///     #[description = "Maximum results to show"]
+///     #[min = 1]
+///     #[max = 25]
+///     #[default = 10]
+///     limit: u8,
+/// }
+/// ```
+#[proc_macro_derive(
+    CommandArguments,
+    attributes(
+        arguments, description, localized_names, localized_names_fn, localized_descriptions,
+        localized_descriptions_fn, rename, autocomplete, choices_fn, skip, min, max, min_length, max_length,
+        channel_types, default, variadic, flatten
+    )
+)]
+pub fn command_arguments(input: TokenStream) -> TokenStream {
+    extract(arguments::arguments(input.into()))
+}
+
 /// Implements the `Modal` trait for the derived struct, allowing it to create modals and collect
 /// the inputs provided by the user.
 ///
@@ -179,36 +379,47 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// use vesper::prelude::*;
 ///
 /// #[derive(Modal)]
+/// #[modal(title = "My modal")]
 /// struct MyModal {
 ///     something: String,
 ///     optional_item: Option<String>
 /// }
 /// ```
 ///
+/// Fields can be of any type implementing `FromStr`, such as numbers or the parsers provided by
+/// the framework. Fields wrapped in an `Option` are not required, and empty inputs are treated as
+/// missing. If a field fails to parse, awaiting the modal returns an error naming the field.
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[derive(Modal)]
+/// #[modal(title = "My modal")]
+/// struct ReminderModal {
+///     delay: parsers::Duration<60>,
+///     repetitions: Option<u8>
+/// }
+/// ```
+///
 /// # Attributes
 ///
 /// The derive macro accepts several attributes:
 ///
-/// - `#[modal(title = "<TITLE>")]`: This attribute allows specifying the title of the modal, by default the
-/// title will be the name of the structure.
+/// - `#[modal(title = "<TITLE>")]`: This attribute specifies the title of the modal, it is required
+///   and must not be empty.
 ///
 /// ## Example
 ///
 /// ```rust
 /// #[derive(Modal)]
-/// struct MyModal { // <- This modal will have "MyModal" as title.
-///     //...
-/// }
-///
-/// #[derive(Modal)]
 /// #[modal(title = "Some incredible modal")]
-/// struct OtherModal { // <- This one will have "Some incredible modal" as the title.
+/// struct MyModal { // <- This modal will have "Some incredible modal" as the title.
 ///     // ...
 /// }
 /// ```
 ///
 /// - `#[modal(label = "<LABEL>")]`: This attribute allows setting the label of the field, by default it will
-/// be the name of the struct field.
+///   be the name of the struct field.
 ///
 /// ## Example
 ///
@@ -216,6 +427,7 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// use vesper::prelude::*;
 ///
 /// #[derive(Modal)]
+/// #[modal(title = "My modal")]
 /// struct MyModal {
 ///     #[modal(label = "My field")]
 ///     something: String, // <- This field will be shown as "My field"
@@ -224,7 +436,7 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// - `#[modal(max_length = x)]` and `#[modal(min_length = y)]`: These attributes allow to set a
-/// maximum/minimum amount of characters a field can have.
+///   maximum/minimum amount of characters a field can have.
 ///
 /// ## Example
 ///
@@ -232,6 +444,7 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// use vesper::prelude::*;
 ///
 /// #[derive(Modal)]
+/// #[modal(title = "My modal")]
 /// struct MyModal {
 ///     #[modal(max_length = 150, min_length = 15)]
 ///     something: String, // <- This field will have both maximum and minimum size constraints.
@@ -242,7 +455,7 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// - #[modal(placeholder = "<PLACEHOLDER>")]: This attribute allows specifying a placeholder that will be seen
-/// before entering anything on the input.
+///   before entering anything on the input.
 /// 
 /// ## Example
 /// 
@@ -250,6 +463,7 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// use vesper::prelude::*;
 ///
 /// #[derive(Modal)]
+/// #[modal(title = "My modal")]
 /// struct MyModal {
 ///     #[modal(placeholder = "This is a placeholder")]
 ///     something: String, // <- This field will have as placeholder "This is a placeholder".
@@ -257,8 +471,8 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// ```
 /// 
 /// - `#[modal(paragraph)]`: This attribute will mark the field as a paragraph. By default, all fields are
-/// marked as single line fields, so the user will only be able to input up to one line unless we
-/// mark it as a paragraph.
+///   marked as single line fields, so the user will only be able to input up to one line unless we
+///   mark it as a paragraph.
 ///
 /// ## Example
 ///
@@ -266,12 +480,53 @@ pub fn parse(input: TokenStream) -> TokenStream {
 /// use vesper::prelude::*;
 ///
 /// #[derive(Modal)]
+/// #[modal(title = "My modal")]
 /// struct MyModal {
 ///     #[modal(paragraph)]
 ///     something: String, // <- This field will be shown as a multi-line field.
 ///     optional_item: Option<String> // <- This one will be shown as a single line one.
 /// }
 /// ```
+///
+/// - `#[modal(validate = <FUNCTION>)]`: This attribute sets a function used to validate the value
+///   of a field after it is parsed, receiving a reference to it. When used on the struct, the
+///   function receives a reference to the whole modal instead. Validators must return a
+///   `Result<(), E>`, being `E` any type implementing `Display`.
+///
+/// When a modal fails to validate, the user receives an ephemeral message containing the error and
+/// a button to open the modal again, pre-filled with the values previously submitted.
+///
+/// ## Example
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// fn not_blank(value: &String) -> Result<(), &'static str> {
+///     if value.trim().is_empty() {
+///         Err("The name can't be blank")
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// fn valid_range(modal: &RangeModal) -> Result<(), String> {
+///     if modal.min > modal.max {
+///         Err(format!("{} is greater than {}", modal.min, modal.max))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Modal)]
+/// #[modal(title = "My modal")]
+/// #[modal(validate = valid_range)]
+/// struct RangeModal {
+///     #[modal(validate = not_blank)]
+///     name: String,
+///     min: i64,
+///     max: i64
+/// }
+/// ```
 #[proc_macro_derive(
     Modal,
     attributes(modal)
@@ -1,14 +1,17 @@
 use darling::{FromDeriveInput, FromField, FromAttributes};
-use proc_macro2::{Ident, TokenStream as TokenStream2};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::ToTokens;
-use syn::{parse2, spanned::Spanned, Error, Result, Type, DeriveInput, Fields, FieldsNamed, Data};
+use syn::{parse2, spanned::Spanned, Error, Result, Type, DeriveInput, Fields, FieldsNamed, Data, Path};
 use crate::optional::Optional;
+use crate::util::is_option;
+use crate::validate;
 
 #[derive(FromDeriveInput, Default)]
 #[darling(attributes(modal))]
 #[darling(default)]
 struct Modal {
     title: String,
+    validate: Option<Path>,
     #[darling(skip)]
     fields: Vec<Field>
 }
@@ -30,7 +33,8 @@ struct FieldAttributes {
     paragraph: bool,
     max_length: Optional<u16>,
     min_length: Optional<u16>,
-    value: Optional<String>
+    value: Optional<String>,
+    validate: Option<Path>
 }
 
 struct FieldParser<'a>(&'a Field);
@@ -39,6 +43,19 @@ impl Modal {
     fn new(input: &DeriveInput, fields: &FieldsNamed) -> darling::Result<Self> {
         let mut this = Self::from_derive_input(input)?;
 
+        let title_span = input.attrs.iter()
+            .find(|attribute| attribute.path().is_ident("modal"))
+            .map(|attribute| attribute.span())
+            .unwrap_or(input.ident.span());
+        validate::length(&this.title, 1, validate::MODAL_TITLE_MAX_LENGTH, "Modal title", title_span)?;
+
+        if fields.named.len() > validate::MODAL_FIELDS_MAX {
+            return Err(Error::new(
+                fields.span(),
+                format!("Modals can't have more than {} fields", validate::MODAL_FIELDS_MAX)
+            ).into());
+        }
+
         for field in &fields.named {
             this.fields.push(Field::new(field)?);
         }
@@ -49,22 +66,57 @@ impl Modal {
 
 impl Field {
     fn new(field: &syn::Field) -> darling::Result<Self> {
-        let mut this = Field::from_field(&field)?;
+        let mut this = Field::from_field(field)?;
         this.attributes = FieldAttributes::from_attributes(field.attrs.as_slice())?;
 
         if this.attributes.label.is_none() {
             this.attributes.label = Some(this.ident.as_ref().unwrap().to_string()).into();
         }
 
+        this.validate(field.span())?;
+
         Ok(this)
     }
+
+    /// Checks the text input created from the field is accepted by discord.
+    fn validate(&self, span: Span) -> Result<()> {
+        let attributes = &self.attributes;
+        let max = validate::TEXT_INPUT_MAX_LENGTH;
+        validate::length(attributes.label.as_ref().unwrap(), 1, validate::LABEL_MAX_LENGTH, "Label", span)?;
+
+        if let Some(placeholder) = &*attributes.placeholder {
+            validate::length(placeholder, 0, validate::PLACEHOLDER_MAX_LENGTH, "Placeholder", span)?;
+        }
+
+        if let Some(value) = &*attributes.value {
+            validate::length(value, 0, max, "Value", span)?;
+        }
+
+        let (min_length, max_length) = (
+            attributes.min_length.as_ref().copied(),
+            attributes.max_length.as_ref().copied()
+        );
+        if min_length.unwrap_or(0) as usize > max || max_length.unwrap_or(1) as usize > max
+            || max_length == Some(0)
+        {
+            return Err(Error::new(span, format!("Lengths must be between 1 and {}", max)));
+        }
+
+        if let (Some(min), Some(max)) = (min_length, max_length) {
+            if min > max {
+                return Err(Error::new(span, "`min_length` can't be greater than `max_length`"));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl ToTokens for Field {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Self {
             ty: kind,
-            ident: _,
+            ident,
             attributes
         } = &self;
         let FieldAttributes {
@@ -73,11 +125,13 @@ impl ToTokens for Field {
             paragraph,
             max_length,
             min_length,
-            value
+            value,
+            ..
         } = attributes;
         let label = label.as_ref().unwrap();
-        let label_ref = &label;
+        let custom_id = ident.as_ref().unwrap().to_string();
         let placeholder = placeholder.clone().map(|p| quote::quote!(String::from(#p)));
+        let required = !is_option(kind);
 
         let style = if *paragraph {
             quote::quote!(TextInputStyle::Paragraph)
@@ -89,13 +143,13 @@ impl ToTokens for Field {
             Component::ActionRow(ActionRow {
                 components: vec![
                     Component::TextInput(TextInput {
-                        custom_id: String::from(#label_ref),
+                        custom_id: String::from(#custom_id),
                         label: String::from(#label),
                         placeholder: #placeholder,
                         style: #style,
                         max_length: #max_length,
                         min_length: #min_length,
-                        required: Some(<#kind as ModalDataOption>::required()),
+                        required: Some(#required),
                         value: #value
                     })
                 ]
@@ -107,10 +161,10 @@ impl ToTokens for Field {
 impl<'a> ToTokens for FieldParser<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ident = &self.0.ident;
-        let label = &self.0.attributes.label.as_ref().unwrap();
+        let custom_id = ident.as_ref().unwrap().to_string();
 
         tokens.extend(quote::quote! {
-            #label => {
+            #custom_id => {
                 #ident = component.value;
             }
         })
@@ -121,25 +175,19 @@ fn fields(data: &Data, derive_span: impl Spanned) -> Result<&FieldsNamed> {
     match data {
         Data::Struct(s) => match &s.fields {
             Fields::Named(fields) => Ok(fields),
-            Fields::Unnamed(fields) => {
-                return Err(Error::new(
-                    fields.span(),
-                    "Tuple structs not supported",
-                ))
-            },
-            Fields::Unit => {
-                return Err(Error::new(
-                    s.fields.span(),
-                    "Unit structs not supported",
-                ))
-            }
-        },
-        _ => {
-            return Err(Error::new(
-                derive_span.span(),
-                "This derive is only available for structs",
+            Fields::Unnamed(fields) => Err(Error::new(
+                fields.span(),
+                "Tuple structs not supported",
+            )),
+            Fields::Unit => Err(Error::new(
+                s.fields.span(),
+                "Unit structs not supported",
             ))
-        }
+        },
+        _ => Err(Error::new(
+            derive_span.span(),
+            "This derive is only available for structs",
+        ))
     }
 }
 
@@ -147,7 +195,7 @@ pub fn modal(input: TokenStream2) -> Result<TokenStream2> {
     let derive = parse2::<DeriveInput>(input)?;
     let fields = fields(&derive.data, &derive)?;
 
-    let Modal { title, fields } = Modal::new(&derive, fields)?;
+    let Modal { title, validate, fields } = Modal::new(&derive, fields)?;
     let struct_ident = &derive.ident;
 
     let parsers = fields.iter()
@@ -156,15 +204,47 @@ pub fn modal(input: TokenStream2) -> Result<TokenStream2> {
     let field_names = fields.iter()
         .map(|field| field.ident.as_ref().unwrap())
         .collect::<Vec<&Ident>>();
-    let field_types = fields.iter()
-        .map(|field| &field.ty)
-        .collect::<Vec<&Type>>();
+    let field_parsers = fields.iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let name = ident.to_string();
+            if is_option(&field.ty) {
+                quote::quote!(::vesper::modal::parse_optional_field(#name, #ident))
+            } else {
+                quote::quote!(::vesper::modal::parse_field(#name, #ident))
+            }
+        })
+        .collect::<Vec<_>>();
+    let field_validators = fields.iter()
+        .filter_map(|field| {
+            let validate = field.attributes.validate.as_ref()?;
+            let ident = field.ident.as_ref().unwrap();
+            let name = ident.to_string();
+
+            Some(quote::quote! {
+                if let Err(error) = #validate(&self.#ident) {
+                    return Err(ModalParseError::Invalid {
+                        field: Some(String::from(#name)),
+                        error: error.to_string()
+                    });
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    let validator = validate.map(|validate| quote::quote! {
+        if let Err(error) = #validate(self) {
+            return Err(ModalParseError::Invalid {
+                field: None,
+                error: error.to_string()
+            });
+        }
+    });
 
     Ok(quote::quote! {
         const _: () = {
             use ::vesper::{
                 context::SlashContext,
-                extract::ModalDataOption,
+                modal::ModalParseError,
                 twilight_exports::{
                     Interaction,
                     InteractionData,
@@ -192,9 +272,9 @@ pub fn modal(input: TokenStream2) -> Result<TokenStream2> {
                     }
                 }
 
-                fn parse(interaction: &mut Interaction) -> Self {
+                fn parse(interaction: &mut Interaction) -> Result<Self, ModalParseError> {
                     let Some(InteractionData::ModalSubmit(modal)) = &mut interaction.data else {
-                        unreachable!();
+                        return Err(ModalParseError::NotAModal);
                     };
 
                     #(let mut #field_names = None;)*
@@ -204,14 +284,20 @@ pub fn modal(input: TokenStream2) -> Result<TokenStream2> {
                         for component in row.components {
                             match component.custom_id.as_str() {
                                 #(#parsers,)*
-                                _ => panic!("Unrecognized field")
+                                other => return Err(ModalParseError::UnknownField(other.to_string()))
                             }
                         }
                     }
 
-                    Self {
-                        #(#field_names: <#field_types as ModalDataOption>::parse(#field_names)),*
-                    }
+                    Ok(Self {
+                        #(#field_names: #field_parsers?),*
+                    })
+                }
+
+                fn validate(&self) -> Result<(), ModalParseError> {
+                    #(#field_validators)*
+                    #validator
+                    Ok(())
                 }
             }
         };
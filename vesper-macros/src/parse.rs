@@ -1,38 +1,125 @@
 use darling::FromAttributes;
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use syn::{spanned::Spanned, DeriveInput, Error, Result};
-use crate::extractors::{Either, FixedList};
+use proc_macro2::{Ident, Literal, Span, TokenStream as TokenStream2};
+use syn::{spanned::Spanned, DeriveInput, Error, Lit, LitStr, Result};
+use crate::extractors::{Either, FixedList, Map};
+use crate::validate;
+
+/// The maximum amount of choices an option can have.
+const MAX_CHOICES: usize = 25;
+
+#[derive(FromAttributes, Default)]
+#[darling(attributes(parse))]
+#[darling(default)]
+struct EnumAttributes {
+    /// The kind of values sent by discord, one of `integer`, `string` or `number`.
+    kind: Option<String>,
+}
 
 #[derive(FromAttributes)]
 #[darling(attributes(parse))]
 struct VariantAttributes {
     #[darling(rename = "rename")]
     renaming: Option<Either<String, FixedList<1, String>>>,
+    /// The value sent by discord when this variant is selected.
+    #[darling(default)]
+    value: Option<Lit>,
+    #[darling(default)]
+    localized_names: Option<Map<LitStr, LitStr>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ChoiceKind {
+    Integer,
+    String,
+    Number,
+}
+
+impl ChoiceKind {
+    fn new(attributes: &EnumAttributes, span: Span) -> Result<Self> {
+        match attributes.kind.as_deref() {
+            None | Some("integer") => Ok(Self::Integer),
+            Some("string") => Ok(Self::String),
+            Some("number") => Ok(Self::Number),
+            Some(_) => Err(Error::new(
+                span,
+                "Choice kind must be one of `integer`, `string` or `number`"
+            ))
+        }
+    }
+
+    /// Gets the value of a variant from the given literal, or its default one if none is given.
+    fn value(&self, lit: Option<&Lit>, index: usize, ident: &Ident) -> Result<Literal> {
+        let error = |span| Err(Error::new(span, "Choice value does not match the enum kind"));
+
+        match (self, lit) {
+            (Self::Integer, None) => Ok(Literal::i64_unsuffixed(index as i64)),
+            (Self::String, None) => Ok(Literal::string(&ident.to_string())),
+            (Self::Number, None) => Ok(Literal::f64_unsuffixed(index as f64)),
+            (Self::Integer, Some(Lit::Int(int))) => Ok(Literal::i64_unsuffixed(int.base10_parse()?)),
+            (Self::String, Some(Lit::Str(string))) => Ok(Literal::string(&string.value())),
+            (Self::Number, Some(Lit::Int(int))) => Ok(Literal::f64_unsuffixed(int.base10_parse()?)),
+            (Self::Number, Some(Lit::Float(float))) => {
+                Ok(Literal::f64_unsuffixed(float.base10_parse()?))
+            },
+            (_, Some(lit)) => error(lit.span())
+        }
+    }
+
+    fn tokens(&self) -> (TokenStream2, TokenStream2, TokenStream2) {
+        match self {
+            Self::Integer => (quote::quote!(i64), quote::quote!(Integer), quote::quote!(Integer)),
+            Self::String => (quote::quote!(String), quote::quote!(String), quote::quote!(String)),
+            Self::Number => (quote::quote!(f64), quote::quote!(Number), quote::quote!(Number)),
+        }
+    }
 }
 
 struct Variant {
-    value: String,
+    name: String,
     ident: Ident,
-    index: usize,
+    value: Literal,
+    localized_names: Option<Map<LitStr, LitStr>>,
 }
 
 impl Variant {
     fn parse_tokens(&self, tokens: &mut TokenStream2) {
-        let index = &self.index;
+        let value = &self.value;
         let ident = &self.ident;
         tokens.extend(quote::quote! {
-            #index => Ok(Self::#ident),
+            if value == #value {
+                return Ok(Self::#ident);
+            }
         })
     }
 
-    fn choice_tokens(&self, tokens: &mut TokenStream2) {
+    fn choice_tokens(&self, tokens: &mut TokenStream2, kind: ChoiceKind) {
+        let name = &self.name;
         let value = &self.value;
-        let index = self.index as i64;
+        let (_, choice_kind, _) = kind.tokens();
+        let value = match kind {
+            ChoiceKind::String => quote::quote!(#value.to_string()),
+            _ => quote::quote!(#value)
+        };
+        let localizations = match &self.localized_names {
+            Some(map) => {
+                let pairs = map.pairs();
+                quote::quote! {
+                    Some(
+                        vec![#(#pairs),*]
+                            .into_iter()
+                            .map(|(k, v): (&str, &str)| (k.to_string(), v.to_string()))
+                            .collect()
+                    )
+                }
+            },
+            None => quote::quote!(None)
+        };
+
         tokens.extend(quote::quote! {
             choices.push(CommandOptionChoice {
-                    name: #value.to_string(),
-                    name_localizations: None,
-                    value: CommandOptionChoiceValue::Integer(#index)
+                    name: #name.to_string(),
+                    name_localizations: #localizations,
+                    value: CommandOptionChoiceValue::#choice_kind(#value)
                 }
             );
         })
@@ -51,10 +138,14 @@ pub fn parse(input: TokenStream2) -> Result<TokenStream2> {
         }
     };
 
+    let kind = ChoiceKind::new(
+        &EnumAttributes::from_attributes(derive.attrs.as_slice())?,
+        derive.ident.span()
+    )?;
+
     let mut variants = Vec::new();
-    let mut index = 1;
 
-    for variant in enumeration.variants {
+    for (index, variant) in (1..).zip(enumeration.variants) {
         if !matches!(&variant.fields, syn::Fields::Unit) {
             return Err(Error::new(
                 variant.span(),
@@ -67,73 +158,125 @@ pub fn parse(input: TokenStream2) -> Result<TokenStream2> {
         let name = attributes.renaming
             .map(|item| item.inner().clone())
             .unwrap_or(variant.ident.to_string());
+        validate::length(&name, 1, validate::CHOICE_NAME_MAX_LENGTH, "Choice name", variant.span())?;
+
+        if let Some(localized_names) = &attributes.localized_names {
+            validate::localized_choice_names(localized_names)?;
+        }
+
+        let value = kind.value(attributes.value.as_ref(), index, &variant.ident)?;
+        if variants.iter().any(|v: &Variant| v.value.to_string() == value.to_string()) {
+            return Err(Error::new(variant.span(), "Choice values must be unique"));
+        }
 
         variants.push(Variant {
+            name,
             ident: variant.ident.clone(),
-            value: name,
-            index,
+            value,
+            localized_names: attributes.localized_names,
         });
-
-        index += 1;
     }
 
     let mut parse_stream = TokenStream2::new();
     let mut choice_stream = TokenStream2::new();
-    for variant in variants {
+    for variant in &variants {
         variant.parse_tokens(&mut parse_stream);
-        variant.choice_tokens(&mut choice_stream);
+        variant.choice_tokens(&mut choice_stream, kind);
     }
 
+    let (value_type, _, option_type) = kind.tokens();
+
+    // Discord only allows 25 choices, so bigger enums are autocompleted instead.
+    let (choices, autocomplete) = if variants.len() > MAX_CHOICES {
+        (quote::quote!(None), quote::quote! {
+            fn autocomplete() -> Option<::vesper::hook::AutocompleteHook<T>> {
+                use ::std::{future::Future, pin::Pin};
+                use ::vesper::{
+                    context::AutocompleteContext,
+                    hook::AutocompleteHook,
+                    twilight_exports::InteractionResponseData,
+                };
+
+                fn complete<'data, T>(ctx: AutocompleteContext<'data, T>)
+                    -> Pin<Box<dyn Future<Output = Option<InteractionResponseData>> + Send + 'data>>
+                {
+                    let input = ctx.user_input.input.to_lowercase();
+                    let matches = |name: &String| name.to_lowercase().contains(&input);
+                    let choices = __choices()
+                        .into_iter()
+                        .filter(|choice| {
+                            matches(&choice.name)
+                                || choice.name_localizations.iter().flat_map(|l| l.values()).any(matches)
+                        })
+                        .take(#MAX_CHOICES)
+                        .collect();
+
+                    Box::pin(async move {
+                        Some(InteractionResponseData {
+                            choices: Some(choices),
+                            ..Default::default()
+                        })
+                    })
+                }
+
+                Some(AutocompleteHook(complete::<T>))
+            }
+        })
+    } else {
+        (quote::quote!(Some(__choices())), TokenStream2::new())
+    };
+
     let enum_name = &derive.ident;
 
     Ok(quote::quote! {
         const _: () = {
             use ::vesper::{
-                builder::WrappedClient,
                 prelude::async_trait,
-                parse::{Parse, ParseError},
+                parse::{Parse, ParseContext, ParseError},
                 twilight_exports::{
-                    CommandInteractionDataResolved,
                     CommandOptionChoice,
                     CommandOptionChoiceValue,
                     CommandOptionType,
                     CommandOptionValue,
-
                 },
             };
 
+            fn __choices() -> Vec<CommandOptionChoice> {
+                let mut choices = Vec::new();
+
+                #choice_stream;
+
+                choices
+            }
+
             #[automatically_derived]
             #[async_trait]
             impl<T: Send + Sync + 'static> Parse<T> for #enum_name {
-                async fn parse(
-                    http_client: &WrappedClient,
-                    data: &T,
-                    value: Option<&CommandOptionValue>,
-                    resolved: Option<&mut CommandInteractionDataResolved>
+                async fn parse_with_context(
+                    ctx: &mut ParseContext<'_, T>,
+                    value: Option<&CommandOptionValue>
                 ) -> Result<Self, ParseError>
                 {
-                    let num = usize::parse(http_client, data, value, resolved).await?;
-                    match num {
-                        #parse_stream
-                        _ => return Err(ParseError::Parsing {
-                                argument_name: String::new(),
-                                required: true,
-                                argument_type: String::from(stringify!(#enum_name)),
-                                error: String::from("Unrecognized option")
-                            }
-                        )
-                    }
+                    let value = #value_type::parse_with_context(ctx, value).await?;
+
+                    #parse_stream
+
+                    Err(ParseError::Parsing {
+                        argument_name: String::new(),
+                        required: <Self as Parse<T>>::required(),
+                        argument_type: String::from(stringify!(#enum_name)),
+                        error: ctx.translate("vesper.parse.option", &[])
+                            .unwrap_or_else(|| String::from("Unrecognized option"))
+                    })
                 }
                 fn kind() -> CommandOptionType {
-                    CommandOptionType::Integer
+                    CommandOptionType::#option_type
                 }
                 fn choices() -> Option<Vec<CommandOptionChoice>> {
-                    let mut choices = Vec::new();
-
-                    #choice_stream;
-
-                    Some(choices)
+                    #choices
                 }
+
+                #autocomplete
             }
         };
     })
@@ -98,7 +98,7 @@ pub fn get_generic_arguments(path: &Path) -> Result<impl Iterator<Item = &Generi
 
 pub fn get_return_type(sig: &Signature) -> Result<Box<Type>> {
     match &sig.output {
-        ReturnType::Default => return Err(Error::new(sig.output.span(), "Return type must be a Result<T, E>")),
+        ReturnType::Default => Err(Error::new(sig.output.span(), "Return type must be a Result<T, E>")),
         ReturnType::Type(_, kind) => Ok(kind.clone())
     }
 }
@@ -160,9 +160,9 @@ pub fn get_bracketed_generic<F>(arg: &FnArg, allow_references: bool, fun: F) ->
 where
     F: Fn(&Type) -> Result<Type>
 {
-    let mut generics = get_generic_arguments(get_path(&get_pat(arg)?.ty, allow_references)?)?;
+    let generics = get_generic_arguments(get_path(&get_pat(arg)?.ty, allow_references)?)?;
 
-    while let Some(next) = generics.next() {
+    for next in generics {
         match next {
             GenericArgument::Lifetime(_) => (),
             GenericArgument::Type(ty) => return Ok(Some(fun(ty)?)),
@@ -197,3 +197,60 @@ pub fn check_return_type(ret: &ReturnType, out: TokenStream) -> Result<()> {
 
     Ok(())
 }
+
+/// Checks whether the given type is one of the context extractors provided by vesper, written
+/// either by its name, as exported by the prelude, or with its full path, like
+/// `vesper::inject::Author`, those are injected from the context without requiring the
+/// `#[inject]` attribute.
+pub fn is_context_extractor(ty: &Type) -> bool {
+    const EXTRACTORS: [&str; 4] = ["Author", "GuildId", "Locale", "State"];
+
+    let Type::Path(ty) = ty else {
+        return false;
+    };
+    let Some(segment) = ty.path.segments.last() else {
+        return false;
+    };
+
+    if segment.ident == "Option" {
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                return is_context_extractor(inner);
+            }
+        }
+    }
+
+    let from_vesper = ty.qself.is_none()
+        && (ty.path.segments.len() == 1 || ty.path.segments[0].ident == "vesper");
+
+    from_vesper && EXTRACTORS.iter().any(|extractor| segment.ident == extractor)
+}
+
+/// Gets the type of the items of a `Vec<T>`, returning `None` if the given type is not a `Vec`.
+pub fn get_vec_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// Checks whether the given type is an `Option<T>`, which makes the argument not required.
+pub fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => ty.path.segments.last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false
+    }
+}
@@ -0,0 +1,130 @@
+//! Checks done at compile time to reject the values discord would reject when registering the
+//! commands, following the same rules as `twilight_validate`.
+
+use proc_macro2::Span;
+use syn::{Error, LitStr, Result};
+use crate::extractors::Map;
+
+/// The maximum length of command, option and choice names.
+pub const NAME_MAX_LENGTH: usize = 32;
+/// The maximum length of command and option descriptions.
+pub const DESCRIPTION_MAX_LENGTH: usize = 100;
+/// The maximum length of choice names.
+pub const CHOICE_NAME_MAX_LENGTH: usize = 100;
+/// The maximum amount of options a command can have.
+pub const OPTIONS_MAX: usize = 25;
+/// The maximum length of modal titles.
+pub const MODAL_TITLE_MAX_LENGTH: usize = 45;
+/// The maximum amount of fields a modal can have.
+pub const MODAL_FIELDS_MAX: usize = 5;
+/// The maximum length of text input labels.
+pub const LABEL_MAX_LENGTH: usize = 45;
+/// The maximum length of text input placeholders.
+pub const PLACEHOLDER_MAX_LENGTH: usize = 100;
+/// The maximum length of text input values.
+pub const TEXT_INPUT_MAX_LENGTH: usize = 4000;
+
+/// The locales supported by discord.
+pub const LOCALES: [&str; 32] = [
+    "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl",
+    "no", "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+    "zh-CN", "ja", "zh-TW", "ko",
+];
+
+/// Checks the given text length is between the given bounds, both inclusive.
+pub fn length(text: &str, min: usize, max: usize, what: &str, span: Span) -> Result<()> {
+    let length = text.chars().count();
+    if length < min || length > max {
+        let message = if min == 0 {
+            format!("{} must be at most {} characters long", what, max)
+        } else {
+            format!("{} must be between {} and {} characters long", what, min, max)
+        };
+
+        return Err(Error::new(span, message));
+    }
+
+    Ok(())
+}
+
+/// Checks the given name is valid for a command, either a chat one or a context menu one.
+pub fn command_name(name: &str, chat: bool, span: Span) -> Result<()> {
+    if chat {
+        chat_name(name, "Command name", span)
+    } else {
+        length(name, 1, NAME_MAX_LENGTH, "Command name", span)
+    }
+}
+
+/// Checks the given name is valid for a chat command or an option, which must be lowercase and
+/// only contain letters, numbers, `-` and `_`.
+pub fn chat_name(name: &str, what: &str, span: Span) -> Result<()> {
+    length(name, 1, NAME_MAX_LENGTH, what, span)?;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() && c != '-' && c != '_' {
+            return Err(Error::new(
+                span,
+                format!("{} can only contain letters, numbers, `-` and `_`, found `{}`", what, c)
+            ));
+        }
+
+        if c.to_lowercase().next() != Some(c) {
+            return Err(Error::new(span, format!("{} must be lowercase", what)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the given description is valid for a chat command or an option.
+pub fn description(description: &str, span: Span) -> Result<()> {
+    length(description, 1, DESCRIPTION_MAX_LENGTH, "Description", span)
+}
+
+/// Checks the given locale is one of the [supported ones](LOCALES).
+pub fn locale(locale: &LitStr) -> Result<()> {
+    let value = locale.value();
+    if !LOCALES.contains(&value.as_str()) {
+        return Err(Error::new(
+            locale.span(),
+            format!("Unknown locale `{}`, expected one of: {}", value, LOCALES.join(", "))
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks the given localized names, using the chat naming rules if `chat` is true.
+pub fn localized_names(map: &Map<LitStr, LitStr>, chat: bool) -> Result<()> {
+    for (locale, name) in map.iter() {
+        self::locale(locale)?;
+        if chat {
+            chat_name(&name.value(), "Localized name", name.span())?;
+        } else {
+            length(&name.value(), 1, NAME_MAX_LENGTH, "Localized name", name.span())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the given localized descriptions.
+pub fn localized_descriptions(map: &Map<LitStr, LitStr>) -> Result<()> {
+    for (locale, description) in map.iter() {
+        self::locale(locale)?;
+        self::description(&description.value(), description.span())?;
+    }
+
+    Ok(())
+}
+
+/// Checks the given localized choice names.
+pub fn localized_choice_names(map: &Map<LitStr, LitStr>) -> Result<()> {
+    for (locale, name) in map.iter() {
+        self::locale(locale)?;
+        length(&name.value(), 1, CHOICE_NAME_MAX_LENGTH, "Choice name", name.span())?;
+    }
+
+    Ok(())
+}
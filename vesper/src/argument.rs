@@ -1,9 +1,124 @@
+use std::fmt::Display;
+use async_trait::async_trait;
 use crate::hook::AutocompleteHook;
+use crate::iter::DataIterator;
 use crate::twilight_exports::*;
-use crate::parse::Parse;
+use crate::parse::{Parse, ParseError};
 use crate::localizations::{Localizations, LocalizationsProvider};
 use crate::prelude::Framework;
 
+const DESCRIPTION_MAX_LENGTH: usize = 100;
+
+/// A pointer to a function returning the choices of an argument, called when the command is
+/// registered and when the argument is received.
+pub type ChoicesProvider<D, T, E> = fn(&Framework<D, T, E>) -> Vec<CommandOptionChoice>;
+
+/// Checks the given value is one of the given choices.
+pub(crate) fn is_choice(choices: &[CommandOptionChoice], value: &CommandOptionValue) -> bool {
+    choices.iter().any(|choice| match (&choice.value, value) {
+        (CommandOptionChoiceValue::Integer(a), CommandOptionValue::Integer(b)) => a == b,
+        (CommandOptionChoiceValue::Number(a), CommandOptionValue::Number(b)) => a == b,
+        (CommandOptionChoiceValue::String(a), CommandOptionValue::String(b)) => a == b,
+        _ => false
+    })
+}
+
+/// A constraint applied to an argument on top of the ones set by its type, used both when
+/// registering the option and when parsing it, as the checks done by discord clients can't be
+/// trusted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentConstraint {
+    /// The minimum value of a numeric argument.
+    MinValue(f64),
+    /// The maximum value of a numeric argument.
+    MaxValue(f64),
+    /// The minimum length of a string argument.
+    MinLength(u16),
+    /// The maximum length of a string argument.
+    MaxLength(u16),
+    /// The channel types allowed in a channel argument.
+    ChannelTypes(Vec<ChannelType>),
+}
+
+impl ArgumentConstraint {
+    /// Applies the constraint to the given option.
+    pub fn apply(&self, option: &mut CommandOption) {
+        use twilight_model::application::command::CommandOptionValue as OptionValue;
+        let integer = option.kind == CommandOptionType::Integer;
+        let value = |value: f64| if integer {
+            OptionValue::Integer(value as i64)
+        } else {
+            OptionValue::Number(value)
+        };
+
+        match self {
+            Self::MinValue(min) => option.min_value = Some(value(*min)),
+            Self::MaxValue(max) => option.max_value = Some(value(*max)),
+            Self::MinLength(min) => option.min_length = Some(*min),
+            Self::MaxLength(max) => option.max_length = Some(*max),
+            Self::ChannelTypes(types) => option.channel_types = Some(types.clone()),
+        }
+    }
+
+    /// Gets the [translation](crate::translator::Translator) key of the message returned when
+    /// the constraint is not satisfied, along with the limit it is formatted with.
+    pub(crate) fn translation(&self) -> (&'static str, String) {
+        match self {
+            Self::MinValue(min) => ("vesper.constraint.min_value", min.to_string()),
+            Self::MaxValue(max) => ("vesper.constraint.max_value", max.to_string()),
+            Self::MinLength(min) => ("vesper.constraint.min_length", min.to_string()),
+            Self::MaxLength(max) => ("vesper.constraint.max_length", max.to_string()),
+            Self::ChannelTypes(_) => ("vesper.constraint.channel_types", String::new()),
+        }
+    }
+
+    /// Checks the value received satisfies the constraint, returning the reason why it doesn't
+    /// otherwise.
+    pub fn check(
+        &self,
+        value: &CommandOptionValue,
+        resolved: Option<&CommandInteractionDataResolved>
+    ) -> Result<(), String> {
+        let number = match value {
+            CommandOptionValue::Integer(i) => Some(*i as f64),
+            CommandOptionValue::Number(n) => Some(*n),
+            _ => None
+        };
+        let length = match value {
+            CommandOptionValue::String(s) => Some(s.chars().count()),
+            _ => None
+        };
+
+        match (self, number, length) {
+            (Self::MinValue(min), Some(number), _) if number < *min => {
+                Err(format!("Value must be at least {}", min))
+            },
+            (Self::MaxValue(max), Some(number), _) if number > *max => {
+                Err(format!("Value must be at most {}", max))
+            },
+            (Self::MinLength(min), _, Some(length)) if length < *min as usize => {
+                Err(format!("Input must be at least {} characters long", min))
+            },
+            (Self::MaxLength(max), _, Some(length)) if length > *max as usize => {
+                Err(format!("Input must be at most {} characters long", max))
+            },
+            (Self::ChannelTypes(types), _, _) => {
+                let CommandOptionValue::Channel(id) = value else {
+                    return Ok(());
+                };
+
+                match resolved.and_then(|resolved| resolved.channels.get(id)) {
+                    Some(channel) if !types.contains(&channel.kind) => {
+                        Err("Channel type not allowed".to_string())
+                    },
+                    _ => Ok(())
+                }
+            },
+            _ => Ok(())
+        }
+    }
+}
+
 /// A structure representing a command argument.
 pub struct CommandArgument<D, T, E> {
     /// Argument name.
@@ -18,9 +133,17 @@ pub struct CommandArgument<D, T, E> {
     pub kind: CommandOptionType,
     /// The input options allowed to choose from in this command, only valid if it is [Some](Some)
     pub choices: Option<Vec<CommandOptionChoice>>,
+    /// A function returning the input options allowed to choose from, replacing the
+    /// [static ones](Self::choices) if present.
+    pub choices_fn: Option<ChoicesProvider<D, T, E>>,
     /// A function used to autocomplete fields.
     pub autocomplete: Option<AutocompleteHook<D>>,
-    pub modify_fn: fn(&mut CommandOption)
+    pub modify_fn: fn(&mut CommandOption),
+    /// Constraints applied to the option after the [modify function](Self::modify_fn).
+    pub constraints: Vec<ArgumentConstraint>,
+    /// The text representation of the value used when the argument is not provided, appended
+    /// to the description of the option.
+    pub default_value: Option<String>
 }
 
 impl<D, T, E> CommandArgument<D, T, E> {
@@ -30,7 +153,7 @@ impl<D, T, E> CommandArgument<D, T, E> {
             autocomplete: None,
             channel_types: None,
             choices: None,
-            description: self.description.to_string(),
+            description: self.full_description(),
             description_localizations: self.localized_descriptions.get_localizations(f, c),
             kind: self.kind,
             max_length: None,
@@ -45,16 +168,47 @@ impl<D, T, E> CommandArgument<D, T, E> {
 
         (self.modify_fn)(&mut option);
 
+        for constraint in &self.constraints {
+            constraint.apply(&mut option);
+        }
+
         match option.kind {
             CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number => {
+                let choices = match self.choices_fn {
+                    Some(fun) => Some(fun(f)),
+                    None => self.choices.clone()
+                };
                 option.autocomplete = Some(self.autocomplete.is_some());
-                option.choices = Some(self.choices.clone().unwrap_or_default());
+                option.choices = Some(choices.unwrap_or_default());
             },
             _ => ()
         }
 
         option
     }
+
+    /// Checks the given value is one of the choices returned by the
+    /// [choices function](Self::choices_fn), always returning `true` if there isn't one.
+    pub fn is_valid_choice(&self, f: &Framework<D, T, E>, value: &CommandOptionValue) -> bool {
+        let Some(fun) = self.choices_fn else {
+            return true;
+        };
+
+        is_choice(&fun(f), value)
+    }
+
+    /// Gets the description of the argument, including its default value if it fits within the
+    /// description length limit.
+    fn full_description(&self) -> String {
+        if let Some(default) = &self.default_value {
+            let description = format!("{} (default: {})", self.description, default);
+            if description.chars().count() <= DESCRIPTION_MAX_LENGTH {
+                return description;
+            }
+        }
+
+        self.description.to_string()
+    }
 }
 
 impl<D: Send + Sync, T, E> CommandArgument<D, T, E> {
@@ -72,11 +226,44 @@ impl<D: Send + Sync, T, E> CommandArgument<D, T, E> {
             required: Arg::required(),
             kind: Arg::kind(),
             choices: Arg::choices(),
-            autocomplete,
-            modify_fn: Arg::modify_option
+            choices_fn: None,
+            autocomplete: autocomplete.or_else(Arg::autocomplete),
+            modify_fn: Arg::modify_option,
+            constraints: Vec::new(),
+            default_value: None
         }
     }
 
+    /// Marks the argument as not required, as a default value is used when it is not provided.
+    pub fn with_default(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Marks the argument as not required, showing the given default value in its description.
+    pub fn default_value(self, value: impl Display) -> Self {
+        self.default_display(Some(value.to_string()))
+    }
+
+    /// Marks the argument as not required, showing the given text in its description if any.
+    #[doc(hidden)]
+    pub fn default_display(mut self, value: Option<String>) -> Self {
+        self.default_value = value;
+        self.with_default()
+    }
+
+    /// Sets the function used to get the choices of the argument.
+    pub fn choices_fn(mut self, fun: ChoicesProvider<D, T, E>) -> Self {
+        self.choices_fn = Some(fun);
+        self
+    }
+
+    /// Adds a [constraint](ArgumentConstraint) to the argument.
+    pub fn constraint(mut self, constraint: ArgumentConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
     pub fn localized_names<I, K, V>(mut self, iterator: I) -> Self
     where
         I: IntoIterator<Item = (K, V)>,
@@ -109,3 +296,16 @@ impl<D: Send + Sync, T, E> CommandArgument<D, T, E> {
         self
     }
 }
+
+/// A group of arguments that can be shared between commands by marking a command parameter with
+/// `#[flatten]`, which expands the group into its options.
+///
+/// This trait is usually implemented using the `CommandArguments` derive macro.
+#[async_trait]
+pub trait CommandArguments<D: Send + Sync>: Sized {
+    /// Creates the [arguments](CommandArgument) registered by this group.
+    fn arguments<T, E>() -> Vec<CommandArgument<D, T, E>>;
+
+    /// Parses the group from the options received in the interaction.
+    async fn parse(iterator: &mut DataIterator<'_, D>) -> Result<Self, ParseError>;
+}
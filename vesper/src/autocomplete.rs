@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use crate::twilight_exports::{CommandOptionChoice, CommandOptionChoiceValue, InteractionResponseData};
+
+/// The maximum amount of choices discord allows to suggest.
+pub const MAX_CHOICES: usize = 25;
+/// The maximum length of the name of a choice.
+pub const CHOICE_NAME_MAX_LENGTH: usize = 100;
+
+/// A value that can be suggested to the user, usually the type of the autocompleted argument.
+pub trait IntoChoiceValue {
+    /// Converts the value into the one sent to discord.
+    fn into_choice_value(self) -> CommandOptionChoiceValue;
+}
+
+impl IntoChoiceValue for String {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::String(self)
+    }
+}
+
+impl IntoChoiceValue for &str {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::String(self.to_string())
+    }
+}
+
+macro_rules! impl_choice_value {
+    ($variant:ident as $as:ty => $($t:ty),* $(,)?) => {
+        $(
+            impl IntoChoiceValue for $t {
+                fn into_choice_value(self) -> CommandOptionChoiceValue {
+                    CommandOptionChoiceValue::$variant(self as $as)
+                }
+            }
+        )*
+    };
+}
+
+impl_choice_value!(Integer as i64 => i8, i16, i32, i64, u8, u16, u32);
+impl_choice_value!(Number as f64 => f32, f64);
+
+/// A suggestion given to the user while autocompleting an argument of type `T`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Choice<T> {
+    /// The name shown to the user.
+    pub name: String,
+    /// The localized names of the choice.
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The value the argument will receive if the choice is selected.
+    pub value: T,
+}
+
+impl<T> Choice<T> {
+    /// Creates a new choice, truncating its name to the maximum length allowed by discord.
+    pub fn new(name: impl Into<String>, value: T) -> Self {
+        Self {
+            name: truncate(name.into()),
+            name_localizations: None,
+            value,
+        }
+    }
+
+    /// Adds the given localized names to the choice.
+    pub fn localized_names<I, K, V>(mut self, iterator: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString
+    {
+        self.name_localizations
+            .get_or_insert_with(HashMap::new)
+            .extend(iterator.into_iter().map(|(k, v)| (k.to_string(), truncate(v.to_string()))));
+        self
+    }
+}
+
+impl<T: IntoChoiceValue> From<Choice<T>> for CommandOptionChoice {
+    fn from(choice: Choice<T>) -> Self {
+        Self {
+            name: truncate(choice.name),
+            name_localizations: choice.name_localizations,
+            value: choice.value.into_choice_value(),
+        }
+    }
+}
+
+/// The values an autocomplete function can return.
+pub trait IntoAutocompleteResponse {
+    /// Converts the value into the data sent to discord, returning `None` if no response should
+    /// be sent.
+    fn into_response(self) -> Option<InteractionResponseData>;
+}
+
+impl IntoAutocompleteResponse for Option<InteractionResponseData> {
+    fn into_response(self) -> Option<InteractionResponseData> {
+        self
+    }
+}
+
+impl IntoAutocompleteResponse for InteractionResponseData {
+    fn into_response(self) -> Option<InteractionResponseData> {
+        Some(self)
+    }
+}
+
+impl IntoAutocompleteResponse for Vec<CommandOptionChoice> {
+    fn into_response(mut self) -> Option<InteractionResponseData> {
+        self.truncate(MAX_CHOICES);
+
+        Some(InteractionResponseData {
+            choices: Some(self),
+            ..Default::default()
+        })
+    }
+}
+
+impl<T: IntoChoiceValue> IntoAutocompleteResponse for Vec<Choice<T>> {
+    fn into_response(self) -> Option<InteractionResponseData> {
+        self.into_iter()
+            .take(MAX_CHOICES)
+            .map(CommandOptionChoice::from)
+            .collect::<Vec<_>>()
+            .into_response()
+    }
+}
+
+/// Ranks the given choices by how well their names match the user input, discarding the ones
+/// not matching it and keeping at most [the amount allowed by discord](MAX_CHOICES).
+///
+/// Exact matches go first, followed by names starting with the input, names having a word
+/// starting with it, names containing it and finally names containing all the input characters
+/// in order. Choices with the same rank keep their original order.
+pub fn rank<T, I>(input: &str, choices: I) -> Vec<Choice<T>>
+where
+    I: IntoIterator<Item = Choice<T>>
+{
+    let input = input.trim().to_lowercase();
+    let mut ranked = choices.into_iter()
+        .filter_map(|choice| score(&input, &choice.name).map(|score| (score, choice)))
+        .collect::<Vec<_>>();
+
+    ranked.sort_by_key(|(score, _)| *score);
+    ranked.into_iter()
+        .take(MAX_CHOICES)
+        .map(|(_, choice)| choice)
+        .collect()
+}
+
+/// Scores how well the given name matches the input, being lower scores better matches.
+fn score(input: &str, name: &str) -> Option<usize> {
+    if input.is_empty() {
+        return Some(0);
+    }
+
+    let name = name.to_lowercase();
+    if name == input {
+        Some(0)
+    } else if name.starts_with(input) {
+        Some(1)
+    } else if name.split(|c: char| !c.is_alphanumeric()).any(|word| word.starts_with(input)) {
+        Some(2)
+    } else if name.contains(input) {
+        Some(3)
+    } else {
+        // Fuzzy matches are ranked by the amount of characters skipped between the input ones.
+        let mut chars = name.chars();
+        let mut skipped = 0;
+        for expected in input.chars() {
+            loop {
+                let c = chars.next()?;
+                if c == expected {
+                    break;
+                }
+                skipped += 1;
+            }
+        }
+
+        Some(4 + skipped)
+    }
+}
+
+fn truncate(mut name: String) -> String {
+    if let Some((index, _)) = name.char_indices().nth(CHOICE_NAME_MAX_LENGTH) {
+        name.truncate(index);
+    }
+
+    name
+}
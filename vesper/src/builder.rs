@@ -1,13 +1,18 @@
 use crate::{
     command::{Command, CommandMap},
+    dedup::{Deduplicator, InMemoryDeduplicator},
     framework::{DefaultError, Framework},
     group::*,
     hook::{AfterHook, BeforeHook},
     twilight_exports::{ApplicationMarker, Client, CommandType, Id, Permissions},
-    parse::ParseError
+    parse::ParseError,
+    translator::{Translator, DEFAULT_LOCALE}
 };
 
-use std::{ops::Deref, sync::Arc};
+use std::{ops::Deref, sync::Arc, time::Duration};
+
+#[cfg(feature = "localizations")]
+use crate::localizations::Catalog;
 
 /// A wrapper around twilight's http client allowing the user to decide how to provide it to the framework.
 #[allow(clippy::large_enum_variant)]
@@ -82,6 +87,19 @@ pub struct FrameworkBuilder<D, T = (), E = DefaultError> {
     pub before: Option<BeforeHook<D>>,
     /// A hook executed after command's completion.
     pub after: Option<AfterHook<D, T, E>>,
+    /// The set used to discard interactions processed more than once.
+    pub deduplicator: Option<Box<dyn Deduplicator>>,
+    /// The time after which commands that didn't respond are deferred automatically.
+    pub defer_watchdog: Option<Duration>,
+    /// The translator used by [`SlashContext::t`] and the messages produced by the framework.
+    ///
+    /// [`SlashContext::t`]: crate::context::SlashContext::t
+    pub translator: Option<Arc<dyn Translator>>,
+    /// The locale used when neither the user nor the guild locales have a translation.
+    pub default_locale: String,
+    /// The catalog used to localize the registered commands.
+    #[cfg(feature = "localizations")]
+    pub catalog: Option<Catalog>,
 }
 
 impl<D, T, E> FrameworkBuilder<D, T, E>
@@ -102,6 +120,12 @@ where
             groups: Default::default(),
             before: None,
             after: None,
+            deduplicator: None,
+            defer_watchdog: None,
+            translator: None,
+            default_locale: DEFAULT_LOCALE.to_string(),
+            #[cfg(feature = "localizations")]
+            catalog: None,
         }
     }
 
@@ -166,6 +190,95 @@ where
         self
     }
 
+    /// Discards interactions that were already processed by the framework, keeping up to
+    /// `capacity` interaction ids in memory for the given amount of time.
+    ///
+    /// Repeated interactions will return [`ProcessResult::Duplicate`] without executing any hook.
+    ///
+    /// [`ProcessResult::Duplicate`]: crate::framework::ProcessResult::Duplicate
+    pub fn deduplicate(self, capacity: usize, ttl: Duration) -> Self {
+        self.deduplicator(InMemoryDeduplicator::new(capacity, ttl))
+    }
+
+    /// Sets the [deduplicator](Deduplicator) used to discard interactions that were already
+    /// processed, allowing to share it across multiple processes.
+    pub fn deduplicator(mut self, deduplicator: impl Deduplicator + 'static) -> Self {
+        self.deduplicator = Some(Box::new(deduplicator));
+        self
+    }
+
+    /// Defers automatically the commands that have not responded to the interaction after the
+    /// given threshold, so discord doesn't invalidate the interaction after 3 seconds.
+    ///
+    /// Once deferred, the command must edit the original response instead of creating one. The
+    /// response is ephemeral for the commands declared with `#[defer(ephemeral)]`.
+    /// Only the responses made using [`SlashContext`] methods are tracked by the watchdog.
+    ///
+    /// [`SlashContext`]: crate::context::SlashContext
+    pub fn defer_watchdog(mut self, threshold: Duration) -> Self {
+        self.defer_watchdog = Some(threshold);
+        self
+    }
+
+    /// Sets the [translator](Translator) used to respond in the locale of the user who executed
+    /// the command, both by [`SlashContext::t`] and the messages produced by the framework.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use vesper::prelude::*;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// #[command]
+    /// #[description = "Greets the user"]
+    /// async fn greet(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     ctx.reply().content(ctx.t("greet.response", &[("user", &"world")])).await?;
+    ///     Ok(())
+    /// }
+    ///
+    /// fn translate(locale: &str, key: &str) -> Option<String> {
+    ///     match (locale, key) {
+    ///         ("es-ES", "greet.response") => Some(String::from("¡Hola {user}!")),
+    ///         (_, "greet.response") => Some(String::from("Hello {user}!")),
+    ///         _ => None
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let token = std::env::var("DISCORD_TOKEN").unwrap();
+    ///     let app_id = std::env::var("DISCORD_APP_ID").unwrap().parse::<u64>().unwrap();
+    ///     let http_client = Client::new(token);
+    ///
+    ///     let framework = Framework::<()>::builder(http_client, Id::new(app_id), ())
+    ///         .command(greet)
+    ///         .translator(translate)
+    ///         .build();
+    /// }
+    /// ```
+    ///
+    /// [`SlashContext::t`]: crate::context::SlashContext::t
+    pub fn translator(mut self, translator: impl Translator + 'static) -> Self {
+        self.translator = Some(Arc::new(translator));
+        self
+    }
+
+    /// Sets the locale used when neither the user nor the guild locales have a translation,
+    /// [`en-US`](DEFAULT_LOCALE) by default.
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = locale.into();
+        self
+    }
+
+    /// Sets the [catalog](Catalog) used to fill the localizations of the registered commands,
+    /// usually loaded with [`Catalog::load`].
+    #[cfg(feature = "localizations")]
+    pub fn localizations(mut self, catalog: Catalog) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
     /// Registers a new command in the framework.
     ///
     /// # Examples
@@ -219,6 +332,7 @@ where
         if self.commands.contains_key(cmd.name) || self.groups.contains_key(cmd.name) {
             panic!("{} already registered", cmd.name);
         }
+        cmd.assert_options();
         self.commands.insert(cmd.name, cmd);
         self
     }
@@ -321,6 +435,7 @@ impl<D, T, E> GroupParentBuilder<D, T, E> {
     pub fn command(&mut self, fun: FnPointer<Command<D, T, E>>) -> &mut Self {
         let command = fun();
         assert!(matches!(command.kind, CommandType::ChatInput), "Only chat commands can be used inside groups");
+        command.assert_options();
         if let ParentType::Simple(map) = &mut self.kind {
             map.insert(command.name, command);
         } else {
@@ -369,6 +484,7 @@ impl<D, T, E> CommandGroupBuilder<D, T, E> {
     pub fn command(&mut self, fun: FnPointer<Command<D, T, E>>) -> &mut Self {
         let command = fun();
         assert!(matches!(command.kind, CommandType::ChatInput), "Only chat commands can be used inside groups");
+        command.assert_options();
         self.subcommands.insert(command.name, command);
         self
     }
@@ -1,19 +1,24 @@
 use crate::localizations::{Localizations, LocalizationsProvider};
 use crate::prelude::{CreateCommandError, Framework};
 use crate::{
-    argument::CommandArgument, context::SlashContext, twilight_exports::Permissions, BoxFuture, framework::ProcessResult,
+    argument::{CommandArgument, CommandArguments}, context::SlashContext, twilight_exports::Permissions, BoxFuture, framework::ProcessResult,
 };
 use std::collections::HashMap;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use twilight_http::client::InteractionClient;
 use twilight_model::id::{marker::GuildMarker, Id};
 use crate::hook::{CheckHook, ErrorHandlerHook};
-use crate::twilight_exports::{Command as TwilightCommand, CommandType};
+use crate::twilight_exports::{Command as TwilightCommand, CommandOption, CommandType};
+
+/// The maximum amount of options a command can have.
+const OPTIONS_MAX: usize = 25;
 
 /// A pointer to a command function.
 pub(crate) type CommandFn<D, T, E> = for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>) -> BoxFuture<'cx, Result<T, E>>;
 /// A map of [commands](self::Command).
 pub type CommandMap<D, T, E> = HashMap<&'static str, Command<D, T, E>>;
+/// The localizations of a name or a description, by locale.
+type LocalizationMap = Option<HashMap<String, String>>;
 
 #[doc(hidden)]
 #[macro_export]
@@ -38,7 +43,7 @@ pub enum ExecutionState {
     /// The error handler raised an error. 
     CommandErrored,
     /// The `before` hook returned `false` and the command didn't execute.
-    BeforeHookFailed
+    BeforeHookFailed,
 }
 
 /// The location of the output of the command.
@@ -86,7 +91,10 @@ pub struct Command<D, T, E> {
     pub nsfw: bool,
     pub only_guilds: bool,
     pub checks: Vec<CheckHook<D, E>>,
-    pub error_handler: Option<ErrorHandlerHook<D, E>>
+    pub error_handler: Option<ErrorHandlerHook<D, E>>,
+    /// Whether the command is deferred before executing, and if so, whether the response will be
+    /// ephemeral.
+    pub defer: Option<bool>
 }
 
 impl<D, T, E> Command<D, T, E> {
@@ -104,7 +112,8 @@ impl<D, T, E> Command<D, T, E> {
             nsfw: false,
             only_guilds: false,
             checks: Default::default(),
-            error_handler: None
+            error_handler: None,
+            defer: None
         }
     }
 
@@ -131,6 +140,15 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
+    /// Adds all the arguments of the given [group](CommandArguments) to the command.
+    pub fn add_arguments<A: CommandArguments<D>>(mut self) -> Self
+    where
+        D: Send + Sync
+    {
+        self.arguments.extend(A::arguments());
+        self
+    }
+
     pub fn checks(mut self, checks: Vec<CheckHook<D, E>>) -> Self {
         self.checks = checks;
         self
@@ -141,6 +159,13 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
+    /// Defers the interaction before executing the command, making the response ephemeral if
+    /// specified.
+    pub fn defer(mut self, ephemeral: bool) -> Self {
+        self.defer = Some(ephemeral);
+        self
+    }
+
     pub fn required_permissions(mut self, permissions: Permissions) -> Self {
         self.required_permissions = Some(permissions);
         self
@@ -188,6 +213,29 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
+    /// Checks the options of the command, including the ones added by flattened
+    /// [groups](CommandArguments), are accepted by discord, panicking otherwise.
+    pub(crate) fn assert_options(&self) {
+        assert!(
+            self.arguments.len() <= OPTIONS_MAX,
+            "Command {} has more than {} options",
+            self.name,
+            OPTIONS_MAX
+        );
+
+        let mut optional_found = false;
+        for argument in &self.arguments {
+            assert!(
+                !(argument.required && optional_found),
+                "Command {} has the required argument {} placed after optional ones",
+                self.name,
+                argument.name
+            );
+
+            optional_found |= !argument.required;
+        }
+    }
+
     pub async fn run_checks<'cx, 'data: 'cx>(&self, context: &'cx mut SlashContext<'data, D>) -> Result<bool, E> {
         debug!("Running command [{}] checks", self.name);
         for check in &self.checks {
@@ -200,6 +248,31 @@ impl<D, T, E> Command<D, T, E> {
         Ok(true)
     }
 
+    /// Gets the options of the command and the localizations of its name and description, filled
+    /// with the ones of the framework [catalog](crate::localizations::Catalog).
+    ///
+    /// Only chat input commands have options and descriptions, so those are empty for other kinds
+    /// of commands.
+    pub(crate) fn localize(
+        &self,
+        framework: &Framework<D, T, E>
+    ) -> (Vec<CommandOption>, LocalizationMap, LocalizationMap) {
+        let mut names = self.localized_names.get_localizations(framework, self);
+
+        if self.kind != CommandType::ChatInput {
+            framework.localize(self.name, &mut names, &mut None, &mut []);
+            return (Vec::new(), names, None);
+        }
+
+        let mut options = self.arguments.iter()
+            .map(|a| a.as_option(framework, self))
+            .collect::<Vec<_>>();
+        let mut descriptions = self.localized_descriptions.get_localizations(framework, self);
+        framework.localize(self.name, &mut names, &mut descriptions, &mut options);
+
+        (options, names, descriptions)
+    }
+
     async fn create_chat_command(
         &self,
         framework: &Framework<D, T, E>,
@@ -207,12 +280,7 @@ impl<D, T, E> Command<D, T, E> {
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
-        let options = self.arguments.iter()
-            .map(|a| a.as_option(framework, self))
-            .collect::<Vec<_>>();
-
-        let name_localizations = self.localized_names.get_localizations(framework, &self);
-        let description_localizations = self.localized_descriptions.get_localizations(framework, &self);
+        let (options, name_localizations, description_localizations) = self.localize(framework);
 
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
@@ -244,16 +312,20 @@ impl<D, T, E> Command<D, T, E> {
 
     async fn create_user_command(
         &self,
+        framework: &Framework<D, T, E>,
         http: &InteractionClient<'_>,
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        let (_, name_localizations, _) = self.localize(framework);
+
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
                 .user(self.name)?
                 .nsfw(self.nsfw);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         } else {
@@ -263,6 +335,7 @@ impl<D, T, E> Command<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         };
@@ -272,16 +345,20 @@ impl<D, T, E> Command<D, T, E> {
 
     async fn create_message_command(
         &self,
+        framework: &Framework<D, T, E>,
         http: &InteractionClient<'_>,
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        let (_, name_localizations, _) = self.localize(framework);
+
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
                 .message(self.name)?
                 .nsfw(self.nsfw);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         } else {
@@ -291,6 +368,7 @@ impl<D, T, E> Command<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         };
@@ -307,8 +385,8 @@ impl<D, T, E> Command<D, T, E> {
     {
         match self.kind {
             CommandType::ChatInput => self.create_chat_command(framework, http, guild).await,
-            CommandType::Message => self.create_message_command(http, guild).await,
-            CommandType::User => self.create_user_command(http, guild).await,
+            CommandType::Message => self.create_message_command(framework, http, guild).await,
+            CommandType::User => self.create_user_command(framework, http, guild).await,
             _ => panic!("Invalid command type")
         }
     }
@@ -319,6 +397,13 @@ impl<D, T, E> Command<D, T, E> {
 
         match self.run_checks(context).await {
             Ok(true) => {
+                if let Some(ephemeral) = self.defer {
+                    debug!("Deferring command [{}]", self.name);
+                    if let Err(why) = context.defer(ephemeral).await {
+                        warn!("Failed to defer command [{}]: {}", self.name, why);
+                    }
+                }
+
                 debug!("Executing command [{}]", self.name);
                 let output = (self.fun)(context).await;
 
@@ -1,12 +1,24 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
 use parking_lot::Mutex;
-use twilight_model::channel::message::MessageFlags;
+use tokio::sync::{oneshot::error::RecvError, Mutex as AsyncMutex};
 use crate::{
     builder::WrappedClient,
+    error::ReplyError,
+    parse::{Parse, ParseContext},
+    response::{self, ReplyBuilder, ReplyTarget, Responder},
+    translator::Translations,
     twilight_exports::*,
     wait::{InteractionWaiter, WaiterWaker}
 };
 
-use crate::modal::{Modal, WaitModal};
+use crate::modal::{parse_modal, Modal, ModalBuilder, WaitModal};
 use crate::wait::new_pair;
 
 /// The value the user is providing to the argument.
@@ -47,8 +59,85 @@ impl<'a, D> AutocompleteContext<'a, D> {
     pub fn http_client(&self) -> &Client {
         self.http_client.inner()
     }
+
+    /// Parses the value the user already provided to another option of the command, returning
+    /// `None` if it is missing, has only been partially entered or fails to parse.
+    pub async fn option<T: Parse<D>>(&mut self, name: &str) -> Option<T>
+    where
+        D: Send + Sync
+    {
+        // Parsers take the entries they use from the resolved data, so a copy of the interaction
+        // is used to allow parsing the same option more than once.
+        let mut interaction = self.interaction.clone();
+        let mut ctx = ParseContext::from_interaction(self.http_client, self.data, None, &mut interaction);
+        let value = ctx.option(name)?;
+
+        if matches!(value, CommandOptionValue::Focused(..)) {
+            return None;
+        }
+
+        T::parse_with_context(&mut ctx, Some(value)).await.ok()
+    }
+
+    /// Parses the input the user is providing to the argument the same way the argument is parsed
+    /// when the command is executed, returning `None` if it is empty or is not a valid value yet.
+    pub async fn focused<T: Parse<D>>(&self) -> Option<T>
+    where
+        D: Send + Sync
+    {
+        let input = self.user_input.input.trim();
+        if input.is_empty() {
+            return None;
+        }
+
+        let value = match self.user_input.kind {
+            CommandOptionType::Integer => CommandOptionValue::Integer(input.parse().ok()?),
+            CommandOptionType::Number => CommandOptionValue::Number(input.parse().ok()?),
+            _ => CommandOptionValue::String(input.to_string())
+        };
+
+        let mut interaction = self.interaction.clone();
+        let mut ctx = ParseContext::from_interaction(self.http_client, self.data, None, &mut interaction);
+
+        T::parse_with_context(&mut ctx, Some(&value)).await.ok()
+    }
+}
+
+/// The state of the response to an interaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResponseState {
+    /// The interaction has not been responded yet.
+    Pending,
+    /// The interaction has been deferred, so the original response must be edited.
+    Deferred,
+    /// The interaction has already been responded.
+    Responded,
 }
 
+/// The time an interaction token remains valid after the interaction is received.
+pub const INTERACTION_TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Checks whether the value given to the argument with the given name is one of its dynamic
+/// choices, computing them only when called.
+pub(crate) type ChoiceValidator<'a> = &'a (dyn Fn(&str, &CommandOptionValue) -> bool + Sync);
+
+/// References to the reference counted framework internals, used to create
+/// [owned contexts](OwnedSlashContext).
+pub(crate) struct SharedRefs<'a, D> {
+    http_client: &'a Arc<WrappedClient>,
+    data: &'a Arc<D>,
+    waiters: &'a Arc<Mutex<Vec<WaiterWaker>>>,
+    translations: &'a Arc<Translations>,
+}
+
+impl<'a, D> Clone for SharedRefs<'a, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, D> Copy for SharedRefs<'a, D> {}
+
 /// Framework context given to all command functions, this struct contains all the necessary
 /// items to respond the interaction and access shared data.
 pub struct SlashContext<'a, D> {
@@ -64,6 +153,13 @@ pub struct SlashContext<'a, D> {
     pub waiters: &'a Mutex<Vec<WaiterWaker>>,
     /// The interaction itself.
     pub interaction: Interaction,
+    /// The state of the response, shared between all clones of this context.
+    pub(crate) response_state: Arc<AsyncMutex<ResponseState>>,
+    /// The moment the interaction was received by the framework.
+    pub(crate) received_at: Instant,
+    pub(crate) shared: SharedRefs<'a, D>,
+    /// Checks the values of the arguments having dynamic choices when parsing them.
+    pub(crate) choices: Option<ChoiceValidator<'a>>,
 }
 
 impl<'a, D> Clone for SlashContext<'a, D> {
@@ -75,6 +171,10 @@ impl<'a, D> Clone for SlashContext<'a, D> {
             data: self.data,
             waiters: self.waiters,
             interaction: self.interaction.clone(),
+            response_state: Arc::clone(&self.response_state),
+            received_at: self.received_at,
+            shared: self.shared,
+            choices: self.choices,
         }
     }
 }
@@ -82,10 +182,11 @@ impl<'a, D> Clone for SlashContext<'a, D> {
 impl<'a, D> SlashContext<'a, D> {
     /// Creates a new context.
     pub(crate) fn new(
-        http_client: &'a WrappedClient,
+        http_client: &'a Arc<WrappedClient>,
         application_id: Id<ApplicationMarker>,
-        data: &'a D,
-        waiters: &'a Mutex<Vec<WaiterWaker>>,
+        data: &'a Arc<D>,
+        waiters: &'a Arc<Mutex<Vec<WaiterWaker>>>,
+        translations: &'a Arc<Translations>,
         interaction: Interaction,
     ) -> Self {
         let interaction_client = http_client.inner().interaction(application_id);
@@ -96,6 +197,59 @@ impl<'a, D> SlashContext<'a, D> {
             data,
             waiters,
             interaction,
+            response_state: Arc::new(AsyncMutex::new(ResponseState::Pending)),
+            received_at: Instant::now(),
+            shared: SharedRefs {
+                http_client,
+                data,
+                waiters,
+                translations,
+            },
+            choices: None,
+        }
+    }
+
+    /// Gets the translator of the framework.
+    pub(crate) fn translations(&self) -> &'a Translations {
+        self.shared.translations
+    }
+
+    /// Converts the context into an [owned context](OwnedSlashContext), which does not borrow
+    /// from the framework and can be moved to other tasks.
+    ///
+    /// As commands receive a reference to the context, it must be cloned first. Both contexts
+    /// share the [state](ResponseState) of the response.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     ctx.defer(false).await?;
+    ///     let owned = ctx.clone().into_owned();
+    ///
+    ///     tokio::spawn(async move {
+    ///         // Do something slow here
+    ///
+    ///         let _ = owned.reply().content("Finished!").await;
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_owned(self) -> OwnedSlashContext<D> {
+        OwnedSlashContext {
+            http_client: Arc::clone(self.shared.http_client),
+            application_id: self.application_id,
+            data: Arc::clone(self.shared.data),
+            waiters: Arc::clone(self.shared.waiters),
+            translations: Arc::clone(self.shared.translations),
+            interaction: self.interaction,
+            response_state: self.response_state,
+            received_at: self.received_at,
         }
     }
 
@@ -104,6 +258,41 @@ impl<'a, D> SlashContext<'a, D> {
         self.http_client.inner()
     }
 
+    /// Translates the given key using the [translator](crate::translator::Translator) of the
+    /// framework, replacing the `{name}` placeholders with the given arguments.
+    ///
+    /// The locale of the user is tried first, then the one of the guild and finally the default
+    /// locale of the framework. If none of them has a translation, the key is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "Greets someone"]
+    /// async fn greet(
+    ///     ctx: &SlashContext<()>,
+    ///     #[description = "The name of the person to greet"] name: String
+    /// ) -> DefaultCommandResult {
+    ///     // Sends "Hello {name}!" translated to the locale of the user.
+    ///     let response = ctx.t("greet.response", &[("name", &name)]);
+    ///     ctx.reply().content(response).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn t(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        translate(self.shared.translations, &self.interaction, key, args)
+    }
+
+    /// Gets the current [state](ResponseState) of the response to the interaction.
+    ///
+    /// Only responses made using the context methods are tracked, responding directly
+    /// with the [interaction client](InteractionClient) won't update the state.
+    pub async fn response_state(&self) -> ResponseState {
+        *self.response_state.lock().await
+    }
+
     /// Gets a mutable reference to the [interaction](Interaction) owned by the context.
     #[deprecated(since = "0.12.0", note = "Use the `interaction` field directly with a mutable context")]
     pub fn interaction_mut(&mut self) -> &mut Interaction {
@@ -112,6 +301,8 @@ impl<'a, D> SlashContext<'a, D> {
 
     /// Defers the interaction, allowing to respond later.
     ///
+    /// If the interaction has already been deferred or responded, this does nothing.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -135,37 +326,89 @@ impl<'a, D> SlashContext<'a, D> {
     /// }
     /// ```
     pub async fn defer(&self, ephemeral: bool) -> Result<(), twilight_http::Error> {
-        self.interaction_client
-            .create_response(
-                self.interaction.id,
-                &self.interaction.token,
-                &InteractionResponse {
-                    kind: InteractionResponseType::DeferredChannelMessageWithSource,
-                    data: if ephemeral {
-                        Some(InteractionResponseData {
-                            flags: Some(MessageFlags::EPHEMERAL),
-                            ..Default::default()
-                        })
-                    } else {
-                        None
-                    },
-                },
-            )
-            .await?;
-
-        Ok(())
+        response::defer(&self.responder(), ephemeral).await
+    }
+
+    pub(crate) fn responder(&self) -> Responder<'a> {
+        Responder {
+            http_client: self.http_client.inner(),
+            application_id: self.application_id,
+            interaction_id: self.interaction.id,
+            token: self.interaction.token.clone(),
+            state: Arc::clone(&self.response_state),
+            expires_at: self.received_at + INTERACTION_TOKEN_LIFETIME,
+        }
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to respond to the interaction.
+    ///
+    /// If the interaction has not been responded, the message will be sent as the response,
+    /// if it has been deferred, the original response will be edited, and if it has already been
+    /// responded, the message will be sent as a followup.
+    ///
+    /// The returned [handle](crate::response::ReplyHandle) can be used to edit or delete the
+    /// message later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     let handle = ctx.reply()
+    ///         .content("Hello world")
+    ///         .ephemeral(true)
+    ///         .await?;
+    ///
+    ///     // Do something here
+    ///
+    ///     handle.edit().content("Goodbye world").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn reply(&self) -> ReplyBuilder<'a> {
+        ReplyBuilder::reply(self.responder())
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to edit the original response to the interaction.
+    ///
+    /// If the interaction has not been responded, the message will be sent as the response.
+    pub fn edit_reply(&self) -> ReplyBuilder<'a> {
+        ReplyBuilder::edit_reply(self.responder())
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to send a followup message.
+    ///
+    /// As followups require the interaction to be responded first, if the interaction has not
+    /// been responded the message will be sent as the response, and if it has been deferred, the
+    /// original response will be edited.
+    pub fn followup(&self) -> ReplyBuilder<'a> {
+        ReplyBuilder::followup(self.responder())
+    }
+
+    /// Deletes the original response to the interaction, returning an error if the interaction
+    /// has not been responded.
+    pub async fn delete_reply(&self) -> Result<(), ReplyError> {
+        response::delete(&self.responder(), ReplyTarget::Original).await
     }
 
     /// Creates a modal that will be prompted to the user in discord, returning a [`WaitModal`] that
     /// can be `.await`ed to retrieve the user input. If the returned [`WaitModal`] is not awaited,
     /// the modal will not close when submitted and the user won't be able to submit the modal.
     ///
+    /// Modals must be the first response to the interaction, so this returns
+    /// [`ReplyError::AlreadyResponded`] if the interaction has been deferred or responded.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use vesper::prelude::*;
     ///
     /// #[derive(Debug, Modal)]
+    /// #[modal(title = "My modal")]
     /// struct MyModal {
     ///     #[modal(paragraph)]
     ///     field: String
@@ -186,16 +429,68 @@ impl<'a, D> SlashContext<'a, D> {
     /// ```
     ///
     /// [`WaitModal`]: WaitModal
-    pub async fn create_modal<M>(&self) -> Result<WaitModal<M>, twilight_http::Error>
+    pub async fn create_modal<M>(&self) -> Result<WaitModal<'_, M>, ReplyError>
     where
         M: Modal<D>
+    {
+        self.create_modal_with::<M, _>(|builder| builder).await
+    }
+
+    /// Creates a modal like [create_modal](Self::create_modal) does, allowing to modify its
+    /// title and the labels, placeholders and values of its fields before sending it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[derive(Debug, Modal)]
+    /// #[modal(title = "Create a tag")]
+    /// struct TagModal {
+    ///     #[modal(paragraph)]
+    ///     content: String
+    /// }
+    ///
+    /// #[command]
+    /// #[description = "Edits a tag"]
+    /// async fn edit_tag(ctx: &SlashContext<()>, #[description = "The tag to edit"] name: String) -> DefaultCommandResult {
+    ///     let current = String::from("The current content of the tag");
+    ///
+    ///     let modal = ctx.create_modal_with::<TagModal, _>(|builder| {
+    ///         builder.title(format!("Editing {}", name))
+    ///             .label("content", ctx.t("tag.content", &[]))
+    ///             .value("content", current)
+    ///     }).await?;
+    ///
+    ///     let data = modal.await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_modal_with<M, F>(&self, fun: F) -> Result<WaitModal<'_, M>, ReplyError>
+    where
+        M: Modal<D>,
+        F: FnOnce(ModalBuilder) -> ModalBuilder
     {
         let modal_id = self.interaction.id.to_string();
+        let builder = ModalBuilder::new(
+            M::create(self, modal_id.clone()),
+            self.interaction.locale.clone()
+        );
+        let response = fun(builder).build();
+
+        let mut state = self.response_state.lock().await;
+        if *state != ResponseState::Pending {
+            return Err(ReplyError::AlreadyResponded);
+        }
+
         self.interaction_client.create_response(
             self.interaction.id,
             &self.interaction.token,
-            &M::create(self, modal_id.clone())
+            &response
         ).await?;
+        *state = ResponseState::Responded;
+        drop(state);
 
         let waiter = self.wait_interaction(move |interaction| {
             let Some(InteractionData::ModalSubmit(data)) = &interaction.data else {
@@ -205,7 +500,339 @@ impl<'a, D> SlashContext<'a, D> {
             data.custom_id == modal_id
         });
 
-        Ok(WaitModal::new(waiter, &self.interaction_client, M::parse))
+        let locales = [self.interaction.locale.as_deref(), self.interaction.guild_locale.as_deref()];
+
+        Ok(WaitModal::new(
+            waiter,
+            &self.interaction_client,
+            self.waiters,
+            response,
+            self.shared.translations,
+            locales,
+            parse_modal::<D, M>
+        ))
+    }
+
+    /// Returns a waiter used to wait for a specific interaction which satisfies the provided
+    /// closure.
+    pub fn wait_interaction<F>(&self, fun: F) -> InteractionWaiter
+    where
+        F: Fn(&Interaction) -> bool + Send + 'static
+    {
+        let (waker, waiter) = new_pair(fun);
+        let mut lock = self.waiters.lock();
+        lock.push(waker);
+        waiter
+    }
+
+    /// Returns a waiter used to wait for a message component interaction which satisfies the
+    /// provided closure, resolving to a [component context](ComponentContext) which can be used
+    /// to respond it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[derive(Debug, Modal)]
+    /// #[modal(title = "Report a message")]
+    /// struct ReportModal {
+    ///     #[modal(paragraph)]
+    ///     reason: String
+    /// }
+    ///
+    /// #[command]
+    /// #[description = "Reports a user"]
+    /// async fn report(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     let button_id = ctx.interaction.id.to_string();
+    ///
+    ///     ctx.reply()
+    ///         .content("Click the button to write the report")
+    ///         .components(vec![/* A button with `button_id` as its custom id */])
+    ///         .await?;
+    ///
+    ///     let component = ctx.wait_component(move |_, data| data.custom_id == button_id).await?;
+    ///     let report = component.create_modal::<ReportModal>().await?.await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn wait_component<F>(&self, fun: F) -> ComponentWaiter<'a, D>
+    where
+        F: Fn(&Interaction, &MessageComponentInteractionData) -> bool + Send + 'static
+    {
+        wait_component(self.shared, self.application_id, fun)
+    }
+}
+
+/// Framework context of a message component interaction, like a button click or a select menu
+/// choice, obtained by awaiting a [component waiter](ComponentWaiter).
+///
+/// The context dereferences to a [slash context](SlashContext) of the component interaction,
+/// so it can reply, defer or create modals the same way commands do.
+pub struct ComponentContext<'a, D> {
+    context: SlashContext<'a, D>,
+    data: MessageComponentInteractionData,
+}
+
+impl<'a, D> Clone for ComponentContext<'a, D> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<'a, D> ComponentContext<'a, D> {
+    /// Creates a new context, the interaction must be a message component interaction.
+    fn new(
+        shared: SharedRefs<'a, D>,
+        application_id: Id<ApplicationMarker>,
+        interaction: Interaction
+    ) -> Self {
+        let data = match &interaction.data {
+            Some(InteractionData::MessageComponent(data)) => data.clone(),
+            _ => unreachable!("Component waiters only match message component interactions"),
+        };
+
+        let context = SlashContext::new(
+            shared.http_client,
+            application_id,
+            shared.data,
+            shared.waiters,
+            shared.translations,
+            interaction
+        );
+
+        Self {
+            context,
+            data,
+        }
+    }
+
+    /// Gets the custom id of the component used.
+    pub fn custom_id(&self) -> &str {
+        &self.data.custom_id
+    }
+
+    /// Gets the type of the component used.
+    pub fn component_type(&self) -> ComponentType {
+        self.data.component_type
+    }
+
+    /// Gets the values selected by the user, empty if the component is not a select menu.
+    pub fn values(&self) -> &[String] {
+        &self.data.values
+    }
+
+    /// Parses the values selected by the user, returning the first error found.
+    pub fn parse_values<T: FromStr>(&self) -> Result<Vec<T>, T::Err> {
+        self.data.values.iter().map(|value| value.parse()).collect()
+    }
+
+    /// Defers the interaction without showing a loading state, so the message containing the
+    /// component can be edited later using [update_message](Self::update_message).
+    pub async fn defer_update(&self) -> Result<(), twilight_http::Error> {
+        response::defer_update(&self.responder()).await
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to edit the message containing the component.
+    ///
+    /// If the interaction has not been responded, the message will be updated as the response,
+    /// otherwise the original response will be edited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// async fn on_click(ctx: ComponentContext<'_, ()>) -> DefaultCommandResult {
+    ///     ctx.update_message()
+    ///         .content(format!("You clicked {}", ctx.custom_id()))
+    ///         .components(vec![])
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn update_message(&self) -> ReplyBuilder<'a> {
+        ReplyBuilder::update_message(self.responder())
+    }
+
+    /// Gets the [slash context](SlashContext) of the component interaction.
+    pub fn into_inner(self) -> SlashContext<'a, D> {
+        self.context
+    }
+}
+
+impl<'a, D> Deref for ComponentContext<'a, D> {
+    type Target = SlashContext<'a, D>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+impl<'a, D> DerefMut for ComponentContext<'a, D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.context
+    }
+}
+
+/// A waiter used to wait for a message component interaction, obtained using
+/// [`SlashContext::wait_component`].
+///
+/// The waiter implements [`Future`], so in order to retrieve the
+/// [component context](ComponentContext), just await the waiter.
+pub struct ComponentWaiter<'a, D> {
+    waiter: InteractionWaiter,
+    shared: SharedRefs<'a, D>,
+    application_id: Id<ApplicationMarker>,
+}
+
+impl<'a, D> Future for ComponentWaiter<'a, D> {
+    type Output = Result<ComponentContext<'a, D>, RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let interaction = ready!(Pin::new(&mut self.waiter).poll(cx))?;
+        Poll::Ready(Ok(ComponentContext::new(self.shared, self.application_id, interaction)))
+    }
+}
+
+/// Registers a waiter for a message component interaction satisfying the given closure.
+fn wait_component<'a, D, F>(
+    shared: SharedRefs<'a, D>,
+    application_id: Id<ApplicationMarker>,
+    fun: F
+) -> ComponentWaiter<'a, D>
+where
+    F: Fn(&Interaction, &MessageComponentInteractionData) -> bool + Send + 'static
+{
+    let (waker, waiter) = new_pair(move |interaction: &Interaction| match &interaction.data {
+        Some(InteractionData::MessageComponent(data)) => fun(interaction, data),
+        _ => false,
+    });
+    shared.waiters.lock().push(waker);
+
+    ComponentWaiter {
+        waiter,
+        shared,
+        application_id,
+    }
+}
+
+/// An owned version of a [slash context](SlashContext), obtained using
+/// [`SlashContext::into_owned`].
+///
+/// This context holds reference counted pointers to the framework internals, so it can be moved
+/// to other tasks to keep responding the interaction until its token expires, 15 minutes after
+/// the interaction was received. Once expired, responding will return
+/// [`ReplyError::TokenExpired`].
+pub struct OwnedSlashContext<D> {
+    /// The http client used by the framework.
+    pub http_client: Arc<WrappedClient>,
+    /// The application id provided to the framework.
+    pub application_id: Id<ApplicationMarker>,
+    /// The data shared across the framework.
+    pub data: Arc<D>,
+    /// Components waiting for an interaction.
+    pub waiters: Arc<Mutex<Vec<WaiterWaker>>>,
+    /// The interaction itself.
+    pub interaction: Interaction,
+    pub(crate) translations: Arc<Translations>,
+    pub(crate) response_state: Arc<AsyncMutex<ResponseState>>,
+    pub(crate) received_at: Instant,
+}
+
+impl<D> Clone for OwnedSlashContext<D> {
+    fn clone(&self) -> Self {
+        Self {
+            http_client: Arc::clone(&self.http_client),
+            application_id: self.application_id,
+            data: Arc::clone(&self.data),
+            waiters: Arc::clone(&self.waiters),
+            interaction: self.interaction.clone(),
+            translations: Arc::clone(&self.translations),
+            response_state: Arc::clone(&self.response_state),
+            received_at: self.received_at,
+        }
+    }
+}
+
+impl<D> OwnedSlashContext<D> {
+    /// Gets the http client used by the framework.
+    pub fn http_client(&self) -> &Client {
+        self.http_client.inner()
+    }
+
+    /// Gets an [interaction client](InteractionClient) made out of the framework's
+    /// [http client](Client).
+    pub fn interaction_client(&self) -> InteractionClient<'_> {
+        self.http_client().interaction(self.application_id)
+    }
+
+    /// Translates the given key in the locale of the user, see [`SlashContext::t`].
+    pub fn t(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        translate(&self.translations, &self.interaction, key, args)
+    }
+
+    /// Gets the moment the interaction token expires.
+    pub fn expires_at(&self) -> Instant {
+        self.received_at + INTERACTION_TOKEN_LIFETIME
+    }
+
+    /// Returns whether the interaction token has expired.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at()
+    }
+
+    /// Gets the current [state](ResponseState) of the response to the interaction.
+    pub async fn response_state(&self) -> ResponseState {
+        *self.response_state.lock().await
+    }
+
+    fn responder(&self) -> Responder<'_> {
+        Responder {
+            http_client: self.http_client(),
+            application_id: self.application_id,
+            interaction_id: self.interaction.id,
+            token: self.interaction.token.clone(),
+            state: Arc::clone(&self.response_state),
+            expires_at: self.expires_at(),
+        }
+    }
+
+    /// Defers the interaction, see [`SlashContext::defer`].
+    pub async fn defer(&self, ephemeral: bool) -> Result<(), ReplyError> {
+        if self.is_expired() {
+            return Err(ReplyError::TokenExpired);
+        }
+
+        Ok(response::defer(&self.responder(), ephemeral).await?)
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to respond to the interaction, see
+    /// [`SlashContext::reply`].
+    pub fn reply(&self) -> ReplyBuilder<'_> {
+        ReplyBuilder::reply(self.responder())
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to edit the original response to the interaction,
+    /// see [`SlashContext::edit_reply`].
+    pub fn edit_reply(&self) -> ReplyBuilder<'_> {
+        ReplyBuilder::edit_reply(self.responder())
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to send a followup message, see
+    /// [`SlashContext::followup`].
+    pub fn followup(&self) -> ReplyBuilder<'_> {
+        ReplyBuilder::followup(self.responder())
+    }
+
+    /// Deletes the original response to the interaction, see [`SlashContext::delete_reply`].
+    pub async fn delete_reply(&self) -> Result<(), ReplyError> {
+        response::delete(&self.responder(), ReplyTarget::Original).await
     }
 
     /// Returns a waiter used to wait for a specific interaction which satisfies the provided
@@ -219,4 +846,33 @@ impl<'a, D> SlashContext<'a, D> {
         lock.push(waker);
         waiter
     }
+
+    /// Returns a waiter used to wait for a message component interaction, see
+    /// [`SlashContext::wait_component`].
+    pub fn wait_component<F>(&self, fun: F) -> ComponentWaiter<'_, D>
+    where
+        F: Fn(&Interaction, &MessageComponentInteractionData) -> bool + Send + 'static
+    {
+        let shared = SharedRefs {
+            http_client: &self.http_client,
+            data: &self.data,
+            waiters: &self.waiters,
+            translations: &self.translations,
+        };
+
+        wait_component(shared, self.application_id, fun)
+    }
+}
+
+/// Translates the given key into the locale of the user who created the interaction, falling
+/// back to the guild locale and then the default one, or returning the key itself.
+fn translate(
+    translations: &Translations,
+    interaction: &Interaction,
+    key: &str,
+    args: &[(&str, &dyn Display)]
+) -> String {
+    let locales = [interaction.locale.as_deref(), interaction.guild_locale.as_deref()];
+    translations.translate(locales, key, args)
+        .unwrap_or_else(|| key.to_string())
 }
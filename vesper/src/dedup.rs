@@ -0,0 +1,81 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use crate::twilight_exports::{Id, InteractionMarker};
+
+/// A set of recently processed interaction ids, used by the framework to discard interactions
+/// delivered more than once.
+///
+/// The framework provides an [in-memory](InMemoryDeduplicator) implementation, but this trait can
+/// be implemented to share the set across multiple processes, for example by using an external
+/// store.
+#[async_trait]
+pub trait Deduplicator: Send + Sync {
+    /// Marks the given interaction as processed, returning `false` if it was already marked.
+    async fn insert(&self, id: Id<InteractionMarker>) -> bool;
+}
+
+/// A [deduplicator](Deduplicator) storing up to `capacity` interaction ids in memory, each one of
+/// them expiring after the provided time to live.
+///
+/// When the set is full, the oldest id is evicted to make room for the new one.
+pub struct InMemoryDeduplicator {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    order: VecDeque<(Id<InteractionMarker>, Instant)>,
+    ids: HashSet<Id<InteractionMarker>>,
+}
+
+impl InMemoryDeduplicator {
+    /// Creates a new deduplicator with the given capacity and time to live.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl Deduplicator for InMemoryDeduplicator {
+    async fn insert(&self, id: Id<InteractionMarker>) -> bool {
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+
+        // Ids are stored in insertion order, so expired ones are always at the front.
+        while let Some((old, inserted)) = entries.order.front().copied() {
+            if now.duration_since(inserted) < self.ttl {
+                break;
+            }
+
+            entries.order.pop_front();
+            entries.ids.remove(&old);
+        }
+
+        if entries.ids.contains(&id) {
+            return false;
+        }
+
+        if self.capacity == 0 {
+            return true;
+        }
+
+        if entries.order.len() >= self.capacity {
+            if let Some((old, _)) = entries.order.pop_front() {
+                entries.ids.remove(&old);
+            }
+        }
+
+        entries.order.push_back((id, now));
+        entries.ids.insert(id);
+
+        true
+    }
+}
@@ -1,5 +1,6 @@
 use thiserror::Error;
 use twilight_validate::command::CommandValidationError;
+use twilight_validate::message::MessageValidationError;
 use twilight_http::{Error as HttpError, response::DeserializeBodyError};
 
 #[non_exhaustive]
@@ -10,3 +11,50 @@ pub enum CreateCommandError {
     Http(#[from] HttpError),
     Deserialize(#[from] DeserializeBodyError)
 }
+
+/// Errors that can be returned when responding to an interaction using a
+/// [reply builder](crate::response::ReplyBuilder).
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ReplyError {
+    #[error(transparent)]
+    Validation(#[from] MessageValidationError),
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error(transparent)]
+    Deserialize(#[from] DeserializeBodyError),
+    /// The interaction has not been responded, so there is no response to delete.
+    #[error("The interaction has not been responded yet")]
+    NotResponded,
+    /// The interaction has already been responded or deferred, so a modal can't be sent.
+    #[error("The interaction has already been responded")]
+    AlreadyResponded,
+    /// The interaction token expired, so the interaction can't be responded anymore.
+    #[error("The interaction token has expired")]
+    TokenExpired
+}
+
+/// Errors that can be returned when loading a [localization catalog](crate::localizations::Catalog).
+#[cfg(feature = "localizations")]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    /// A catalog file could not be read.
+    #[error("Failed to read {}: {source}", path.display())]
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error
+    },
+    /// A catalog is not valid json.
+    #[error("Failed to parse the catalog of locale {locale}: {source}")]
+    Json {
+        locale: String,
+        source: serde_json::Error
+    },
+    /// A catalog contains a value which is neither a string nor an object.
+    #[error("The value of key {key} in locale {locale} is not a string")]
+    InvalidValue {
+        locale: String,
+        key: String
+    }
+}
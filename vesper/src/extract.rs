@@ -1,11 +1,9 @@
+use std::fmt::Display;
+
 mod sealed {
     pub trait Sealed {}
     impl<T, E> Sealed for Result<T, E> {}
     impl<T> Sealed for Option<T> {}
-
-    pub trait SealedDataOption: Sized {}
-    impl SealedDataOption for String {}
-    impl SealedDataOption for Option<String> {}
 }
 
 /// Defines what items are allowed to be returned from a command function. Since a command
@@ -24,12 +22,6 @@ pub trait Optional: sealed::Sealed {
     type Inner;
 }
 
-/// Defines what data types can be used when creating a modal.
-pub trait ModalDataOption: sealed::SealedDataOption {
-    fn required() -> bool;
-    fn parse(item: Option<String>) -> Self;
-}
-
 impl<T, E> Returnable for Result<T, E> {
     type Ok = T;
     type Err = E;
@@ -39,26 +31,31 @@ impl<T> Optional for Option<T> {
     type Inner = T;
 }
 
-impl ModalDataOption for Option<String> {
-    fn required() -> bool {
-        false
-    }
+/// Wraps the default value of an argument to get its text representation, which is only
+/// available when the value implements [`Display`] and is not empty.
+///
+/// Calling `(&DefaultDisplay(&value)).display_default()` with both [`DisplayDefault`] and
+/// [`NoDisplayDefault`] in scope picks the first one when possible.
+pub struct DefaultDisplay<'a, T>(pub &'a T);
 
-    fn parse(item: Option<String>) -> Self {
-        if item.as_ref()?.is_empty() {
-            None
-        } else {
-            item
-        }
-    }
+/// Gets the text representation of a default value implementing [`Display`].
+pub trait DisplayDefault {
+    fn display_default(&self) -> Option<String>;
 }
 
-impl ModalDataOption for String {
-    fn required() -> bool {
-        true
+impl<T: Display> DisplayDefault for DefaultDisplay<'_, T> {
+    fn display_default(&self) -> Option<String> {
+        Some(self.0.to_string()).filter(|display| !display.is_empty())
     }
+}
+
+/// Fallback used for default values not implementing [`Display`].
+pub trait NoDisplayDefault {
+    fn display_default(&self) -> Option<String>;
+}
 
-    fn parse(item: Option<String>) -> Self {
-        item.expect("Item can't be null")
+impl<T> NoDisplayDefault for &DefaultDisplay<'_, T> {
+    fn display_default(&self) -> Option<String> {
+        None
     }
 }
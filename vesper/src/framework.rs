@@ -3,22 +3,31 @@ use crate::{
     builder::{FrameworkBuilder, WrappedClient},
     command::{Command, CommandMap, ExecutionState, OutputLocation},
     context::{AutocompleteContext, Focused, SlashContext},
+    dedup::Deduplicator,
     group::GroupParentMap,
     hook::{AfterHook, BeforeHook},
     twilight_exports::{
         ApplicationMarker, Client,
-        Command as TwilightCommand, CommandDataOption, CommandOptionType,
+        Command as TwilightCommand, CommandDataOption, CommandOption, CommandOptionType,
         CommandOptionValue, GuildMarker, Id, Interaction, InteractionData, InteractionType, InteractionClient, InteractionResponse,
         InteractionResponseType,
     },
+    translator::Translations,
     wait::WaiterWaker, prelude::CreateCommandError
 };
-use tracing::debug;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::future::{poll_fn, Future};
+use std::task::Poll;
+use std::time::Duration;
+use tracing::{debug, warn};
 use parking_lot::Mutex;
 use crate::command::ExecutionResult;
 use crate::parse::ParseError;
 #[cfg(feature = "bulk")]
 use crate::if_some;
+#[cfg(feature = "localizations")]
+use crate::localizations::Catalog;
 
 macro_rules! extract {
     ($expr:expr => $variant:ident) => {
@@ -44,7 +53,9 @@ pub enum ProcessResult<T, E> {
     /// The specified command was executed.
     CommandExecuted(ExecutionResult<T, E>),
     /// The interaction type is not supported. This should unly happen with `Ping` interactions.
-    UnknownInteraction
+    UnknownInteraction,
+    /// The interaction was already processed by the framework, so it was discarded.
+    Duplicate
 }
 
 /// The default error used by the framework.
@@ -56,11 +67,11 @@ pub type DefaultCommandResult = Result<(), DefaultError>;
 /// The framework used to dispatch slash commands.
 pub struct Framework<D, T = (), E = DefaultError> {
     /// The http client used by the framework.
-    pub http_client: WrappedClient,
+    pub http_client: Arc<WrappedClient>,
     /// The application id of the client.
     pub application_id: Id<ApplicationMarker>,
     /// Data shared across all command and hook invocations.
-    pub data: D,
+    pub data: Arc<D>,
     /// A map of simple commands.
     pub commands: CommandMap<D, T, E>,
     /// A map of command groups including all children.
@@ -69,7 +80,16 @@ pub struct Framework<D, T = (), E = DefaultError> {
     pub before: Option<BeforeHook<D>>,
     /// A hook executed after command's execution.
     pub after: Option<AfterHook<D, T, E>>,
-    pub waiters: Mutex<Vec<WaiterWaker>>
+    pub waiters: Arc<Mutex<Vec<WaiterWaker>>>,
+    /// The set used to discard interactions processed more than once.
+    pub deduplicator: Option<Box<dyn Deduplicator>>,
+    /// The time after which commands that didn't respond are deferred automatically.
+    pub defer_watchdog: Option<Duration>,
+    /// The translator used to respond in the locale of the users.
+    pub(crate) translations: Arc<Translations>,
+    /// The catalog used to localize the registered commands.
+    #[cfg(feature = "localizations")]
+    pub catalog: Option<Catalog>
 }
 
 impl<D, T, E> Framework<D, T, E>
@@ -78,14 +98,22 @@ where
 {
     pub(crate) fn from_builder(builder: FrameworkBuilder<D, T, E>) -> Self {
         Self {
-            http_client: builder.http_client,
+            http_client: Arc::new(builder.http_client),
             application_id: builder.application_id,
-            data: builder.data,
+            data: Arc::new(builder.data),
             commands: builder.commands,
             groups: builder.groups,
             before: builder.before,
             after: builder.after,
-            waiters: Mutex::new(Vec::new())
+            waiters: Arc::new(Mutex::new(Vec::new())),
+            deduplicator: builder.deduplicator,
+            defer_watchdog: builder.defer_watchdog,
+            translations: Arc::new(Translations {
+                translator: builder.translator,
+                default_locale: builder.default_locale
+            }),
+            #[cfg(feature = "localizations")]
+            catalog: builder.catalog
         }
     }
 
@@ -106,15 +134,25 @@ where
 
     /// Gets the [interaction client](InteractionClient) using this framework's
     /// [http client](Client) and [application id](ApplicationMarker)
-    pub fn interaction_client(&self) -> InteractionClient {
+    pub fn interaction_client(&self) -> InteractionClient<'_> {
         self.http_client().interaction(self.application_id)
     }
 
     /// Processes the given interaction, dispatching commands or waking waiters if necessary.
-    pub async fn process(&self, mut interaction: Interaction) -> ProcessResult<T, E> {
+    pub async fn process(&self, interaction: Interaction) -> ProcessResult<T, E>
+    where
+        D: Send + Sync
+    {
+        if let Some(deduplicator) = &self.deduplicator {
+            if !deduplicator.insert(interaction.id).await {
+                debug!("Discarding already processed interaction {}", interaction.id);
+                return ProcessResult::Duplicate;
+            }
+        }
+
         match interaction.kind {
             InteractionType::ApplicationCommand => {
-                let Some(command) = self.get_command(&mut interaction) else {
+                let Some(command) = self.get_command(&interaction) else {
                     self.wake_waiters(interaction);
                     return ProcessResult::CommandNotFound;
                 };
@@ -145,7 +183,7 @@ where
             if let Some(fun) = &argument.autocomplete {
                 let context = AutocompleteContext::new(
                     &self.http_client,
-                    &self.data,
+                    &*self.data,
                     value,
                     &mut interaction,
                 );
@@ -177,10 +215,10 @@ where
     ) -> Option<(&str, &CommandArgument<D, T, E>, Focused)> {
         let data = extract!(interaction.data.as_ref().unwrap() => ApplicationCommand);
         if !data.options.is_empty() {
-            let outer = data.options.get(0)?;
+            let outer = data.options.first()?;
             let focused = match &outer.value {
                 CommandOptionValue::SubCommandGroup(sc_group) => {
-                    let next = sc_group.get(0)?;
+                    let next = sc_group.first()?;
                     if let CommandOptionValue::SubCommand(options) = &next.value {
                         self.get_focus(options)
                     } else {
@@ -252,27 +290,43 @@ where
 
     /// Gets the next [option](CommandDataOption)
     /// only if it corresponds to a subcommand or a subcommand group.
-    fn get_next<'a>(&self, interaction: &'a Vec<CommandDataOption>) -> Option<&'a CommandDataOption> {
+    fn get_next<'a>(&self, interaction: &'a [CommandDataOption]) -> Option<&'a CommandDataOption> {
         if !interaction.is_empty()
             && (interaction[0].value.kind() == CommandOptionType::SubCommand
                 || interaction[0].value.kind() == CommandOptionType::SubCommandGroup)
         {
-            interaction.get(0)
+            interaction.first()
         } else {
             None
         }
     }
 
     /// Executes the given [command](crate::command::Command) and the hooks.
-    async fn execute(&self, cmd: &Command<D, T, E>, interaction: Interaction) -> ExecutionResult<T, E> {
+    async fn execute(&self, cmd: &Command<D, T, E>, interaction: Interaction) -> ExecutionResult<T, E>
+    where
+        D: Send + Sync
+    {
+        // Discord clients can't be trusted to only send the registered choices, so the dynamic
+        // ones are computed and checked only when parsing the arguments having them.
+        let is_valid_choice = |name: &str, value: &CommandOptionValue| {
+            cmd.arguments.iter()
+                .filter(|argument| argument.name == name)
+                .all(|argument| argument.is_valid_choice(self, value))
+        };
+
         let mut context = SlashContext::new(
             &self.http_client,
             self.application_id,
             &self.data,
             &self.waiters,
+            &self.translations,
             interaction,
         );
 
+        if cmd.arguments.iter().any(|argument| argument.choices_fn.is_some()) {
+            context.choices = Some(&is_valid_choice);
+        }
+
         let execute = if let Some(before) = &self.before {
             (before.0)(&mut context, cmd.name).await
         } else {
@@ -280,29 +334,32 @@ where
         };
 
         if execute {
-            let mut result = cmd.execute(&mut context).await;
-
-            match (&self.after, result.state) {
-                // The after hook should not execute if any check returned false or a check errored.
-                (Some(after), 
-                ExecutionState::CommandFinished 
-                | ExecutionState::CommandErrored) => {
-                    // Set the output as taken, if it was already taken, we'll restore it to the previous state.
-                    let output = std::mem::replace(&mut result.output, OutputLocation::TakenByAfterHook);
-
-                    let output = if let OutputLocation::Present(return_value) = output {
-                        // If the output is not taken beforehand by the error handler, leave it as taken
-                        // by the after hook one.
-                        Some(return_value)
-                    } else {
-                        // If it was taken, return it to it's previous state.
-                        result.output = output;
-                        None
-                    };
+            let mut result = if let Some(threshold) = self.defer_watchdog {
+                let watchdog = context.clone();
+                let ephemeral = cmd.defer.unwrap_or(false);
+                Self::watch(watchdog, threshold, ephemeral, cmd.execute(&mut context)).await
+            } else {
+                cmd.execute(&mut context).await
+            };
+
+            // The after hook should not execute if any check returned false or a check errored.
+            if let (Some(after), ExecutionState::CommandFinished | ExecutionState::CommandErrored) =
+                (&self.after, result.state)
+            {
+                // Set the output as taken, if it was already taken, we'll restore it to the previous state.
+                let output = std::mem::replace(&mut result.output, OutputLocation::TakenByAfterHook);
+
+                let output = if let OutputLocation::Present(return_value) = output {
+                    // If the output is not taken beforehand by the error handler, leave it as taken
+                    // by the after hook one.
+                    Some(return_value)
+                } else {
+                    // If it was taken, return it to it's previous state.
+                    result.output = output;
+                    None
+                };
 
-                    (after.0)(&mut context, cmd.name, output).await;
-                },
-                _ => ()
+                (after.0)(&mut context, cmd.name, output).await;
             }
 
             result
@@ -314,6 +371,42 @@ where
         }
     }
 
+    /// Drives the given future to completion, deferring the interaction if it has not been
+    /// responded after the given threshold, making the response ephemeral if specified.
+    async fn watch<F: Future>(
+        context: SlashContext<'_, D>,
+        threshold: Duration,
+        ephemeral: bool,
+        fut: F
+    ) -> F::Output {
+        let watchdog = async {
+            tokio::time::sleep(threshold).await;
+            debug!("Watchdog threshold reached for interaction {}", context.interaction.id);
+
+            // Deferring does nothing if the command already responded.
+            if let Err(why) = context.defer(ephemeral).await {
+                warn!("Failed to defer interaction {}: {}", context.interaction.id, why);
+            }
+        };
+
+        tokio::pin!(fut);
+        tokio::pin!(watchdog);
+        let mut watchdog_finished = false;
+
+        // Keep polling both futures so the command can still make progress while deferring.
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                return Poll::Ready(output);
+            }
+
+            if !watchdog_finished {
+                watchdog_finished = watchdog.as_mut().poll(cx).is_ready();
+            }
+
+            Poll::Pending
+        }).await
+    }
+
     /// Registers the commands provided to the framework in the specified guild.
     pub async fn register_guild_commands(
         &self,
@@ -324,11 +417,11 @@ where
         for cmd in self.commands.values() {
             debug!("Registering command [{}]", cmd.name);
 
-            commands.push(cmd.create(&self, &self.interaction_client(), Some(guild_id)).await?);
+            commands.push(cmd.create(self, &self.interaction_client(), Some(guild_id)).await?);
         }
 
         for group in self.groups.values() {
-            commands.push(group.create(&self, &self.interaction_client(), Some(guild_id)).await?);
+            commands.push(group.create(self, &self.interaction_client(), Some(guild_id)).await?);
         }
 
         Ok(commands)
@@ -341,11 +434,11 @@ where
         let mut commands = Vec::new();
 
         for cmd in self.commands.values() {
-            commands.push(cmd.create(&self, &self.interaction_client(), None).await?);
+            commands.push(cmd.create(self, &self.interaction_client(), None).await?);
         }
 
         for group in self.groups.values() {
-            commands.push(group.create(&self, &self.interaction_client(), None).await?);
+            commands.push(group.create(self, &self.interaction_client(), None).await?);
         }
 
         Ok(commands)
@@ -370,21 +463,21 @@ where
             };
 
             let mut command = CommandBuilder::new(cmd.name, description, cmd.kind);
+            // only chat input commands can have options and descriptions, so those are empty
+            // for other kinds of commands
+            let (options, names, descriptions) = cmd.localize(self);
 
-            // only chat input commands can have options and descriptions
-            if cmd.kind == CommandType::ChatInput {
-                for i in &cmd.arguments {
-                    command = command.option(i.as_option(self, cmd));
-                }
-                //if_some!(&cmd.localized_descriptions, |d| command = command.name_localizations(d));
-                if let Some(localizations) = cmd.localized_descriptions.get_localizations(self, cmd) {
-                    command = command.description_localizations(localizations);
-                }
+            for i in options {
+                command = command.option(i);
+            }
+            //if_some!(&cmd.localized_descriptions, |d| command = command.name_localizations(d));
+            if let Some(localizations) = descriptions {
+                command = command.description_localizations(localizations);
             }
 
             if_some!(cmd.required_permissions, |p| command = command.default_member_permissions(p));
             //if_some!(&cmd.localized_names, |n| command = command.name_localizations(n));
-            if let Some(localizations) = cmd.localized_names.get_localizations(self, cmd) {
+            if let Some(localizations) = names {
                 command = command.name_localizations(localizations);
             }
             
@@ -393,7 +486,9 @@ where
         }
 
         for group in self.groups.values() {
-            let options = group.get_options(self);
+            let mut options = group.get_options(self);
+            let (mut names, mut descriptions) = (None, None);
+            self.localize(group.name, &mut names, &mut descriptions, &mut options);
             // groups are only supported by chat input
             let mut command = CommandBuilder::new(group.name, group.description, CommandType::ChatInput);
 
@@ -402,6 +497,8 @@ where
             }
 
             if_some!(group.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(names, |n| command = command.name_localizations(n));
+            if_some!(descriptions, |d| command = command.description_localizations(d));
 
             commands.push(command.build());
         }
@@ -409,3 +506,21 @@ where
         commands
     }
 }
+
+impl<D, T, E> Framework<D, T, E> {
+    /// Fills the given localizations of a command, and the ones of its options, with the ones
+    /// present in the [catalog](crate::localizations::Catalog), if any.
+    #[allow(unused_variables)]
+    pub(crate) fn localize(
+        &self,
+        path: &str,
+        names: &mut Option<HashMap<String, String>>,
+        descriptions: &mut Option<HashMap<String, String>>,
+        options: &mut [CommandOption]
+    ) {
+        #[cfg(feature = "localizations")]
+        if let Some(catalog) = &self.catalog {
+            catalog.localize(path, names, descriptions, options);
+        }
+    }
+}
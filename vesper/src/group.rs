@@ -83,7 +83,9 @@ impl<D, T, E> GroupParent<D, T, E> {
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
-        let options = self.get_options(framework);
+        let mut options = self.get_options(framework);
+        let (mut names, mut descriptions) = (None, None);
+        framework.localize(self.name, &mut names, &mut descriptions, &mut options);
 
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
@@ -92,6 +94,8 @@ impl<D, T, E> GroupParent<D, T, E> {
                 .nsfw(self.nsfw);
 
             crate::if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            crate::if_some!(&names, |n| command = command.name_localizations(n)?);
+            crate::if_some!(&descriptions, |d| command = command.description_localizations(d)?);
 
             command.await?.model().await?
         } else {
@@ -102,6 +106,8 @@ impl<D, T, E> GroupParent<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             crate::if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            crate::if_some!(&names, |n| command = command.name_localizations(n)?);
+            crate::if_some!(&descriptions, |d| command = command.description_localizations(d)?);
 
             command.await?.model().await?
         };
@@ -0,0 +1,116 @@
+use std::ops::Deref;
+use twilight_model::user::User;
+use crate::{context::SlashContext, parse::ParseError, twilight_exports::*};
+
+/// A value that can be extracted directly from the [context](SlashContext) of a command.
+///
+/// Command parameters whose type implements this trait are resolved from the context instead of
+/// being registered as command options. The `#[command]` macro recognises them when marked with
+/// `#[inject]`, or when their type is one of [`Author`], [`GuildId`], [`Locale`] or [`State`],
+/// either by its name or its full path, like `vesper::inject::Author`.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "Greets the user"]
+/// async fn hello(ctx: &SlashContext<()>, author: Author) -> DefaultCommandResult {
+///     ctx.reply().content(format!("Hello {}!", author.0.name)).await?;
+///     Ok(())
+/// }
+/// ```
+pub trait FromContext<'a, D>: Sized {
+    /// Extracts the value from the given context.
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError>;
+}
+
+/// Creates the error returned when a value is missing from the context, translating its message
+/// with the given key if possible.
+fn missing_context<D>(ctx: &SlashContext<'_, D>, key: &str, message: &str) -> ParseError {
+    let locales = [ctx.interaction.locale.as_deref(), ctx.interaction.guild_locale.as_deref()];
+    let message = ctx.translations().translate(locales, key, &[])
+        .unwrap_or_else(|| message.to_string());
+    ParseError::MissingContext(message)
+}
+
+/// The user who executed the command.
+#[derive(Debug, Clone)]
+pub struct Author(pub User);
+
+impl<'a, D> FromContext<'a, D> for Author {
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError> {
+        ctx.interaction.author()
+            .cloned()
+            .map(Self)
+            .ok_or_else(|| missing_context(ctx, "vesper.context.author", "Interaction has no author"))
+    }
+}
+
+/// The id of the guild the command was executed in.
+///
+/// Extracting this value fails when the command is executed outside a guild, use
+/// `Option<GuildId>` to allow that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GuildId(pub Id<GuildMarker>);
+
+impl<'a, D> FromContext<'a, D> for GuildId {
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError> {
+        ctx.interaction.guild_id
+            .map(Self)
+            .ok_or_else(|| missing_context(ctx, "vesper.context.guild", "Command must be used in a guild"))
+    }
+}
+
+/// The locale selected by the user who executed the command.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(pub String);
+
+impl<'a, D> FromContext<'a, D> for Locale {
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError> {
+        ctx.interaction.locale
+            .clone()
+            .map(Self)
+            .ok_or_else(|| missing_context(ctx, "vesper.context.locale", "Interaction has no locale"))
+    }
+}
+
+/// A reference to a part of the data shared across the framework, available when the framework
+/// data implements `AsRef<T>`.
+pub struct State<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> Clone for State<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for State<'a, T> {}
+
+impl<'a, T: ?Sized> Deref for State<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a, D, T> FromContext<'a, D> for State<'a, T>
+where
+    D: AsRef<T>,
+    T: ?Sized + 'a
+{
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError> {
+        Ok(Self(<D as AsRef<T>>::as_ref(ctx.data)))
+    }
+}
+
+impl<'a, D, T> FromContext<'a, D> for Option<T>
+where
+    T: FromContext<'a, D>
+{
+    fn from_context(ctx: &SlashContext<'a, D>) -> Result<Self, ParseError> {
+        Ok(T::from_context(ctx).ok())
+    }
+}
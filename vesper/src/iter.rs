@@ -1,33 +1,58 @@
-use crate::builder::WrappedClient;
-use crate::context::SlashContext;
-use crate::parse::{Parse, ParseError};
-use crate::twilight_exports::{InteractionData, CommandDataOption, CommandOptionType, CommandOptionValue, CommandInteractionDataResolved};
+use crate::argument::ArgumentConstraint;
+use crate::context::{ChoiceValidator, SlashContext};
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::twilight_exports::{
+    CommandDataOption, CommandOptionType, CommandOptionValue, CommandInteractionDataResolved
+};
 
 /// An iterator used to iterate through slash command options.
 pub struct DataIterator<'a, D> {
     src: Vec<&'a CommandDataOption>,
-    resolved: &'a mut Option<CommandInteractionDataResolved>,
-    http: &'a WrappedClient,
-    data: &'a D
+    ctx: ParseContext<'a, D>,
+    choices: Option<ChoiceValidator<'a>>
 }
 
 impl<'a, D> DataIterator<'a, D> {
     /// Creates a new [iterator](self::DataIterator) at the given source.
     pub fn new(ctx: &'a mut SlashContext<'_, D>) -> Self {
-        let data = match ctx.interaction.data.as_mut().unwrap() {
-            InteractionData::ApplicationCommand(data) => data,
-            _ => unreachable!()
-        };
+        let choices = ctx.choices;
+        let translations = ctx.translations();
+        let ctx = ParseContext::from_interaction(
+            ctx.http_client,
+            ctx.data,
+            Some(translations),
+            &mut ctx.interaction
+        );
+
+        let options = ctx.options;
 
         Self {
-            src: Self::get_data(&data.options),
-            resolved: &mut data.resolved,
-            http: ctx.http_client,
-            data: ctx.data
+            src: options.iter().collect(),
+            ctx,
+            choices
         }
     }
 }
 
+/// Gets the options provided to the executed command, skipping all the subcommands and
+/// subcommand groups.
+pub(crate) fn get_data(options: &[CommandDataOption]) -> &[CommandDataOption] {
+    if let Some(index) = options.iter().position(|item| {
+        item.value.kind() == CommandOptionType::SubCommand
+            || item.value.kind() == CommandOptionType::SubCommandGroup
+    })
+    {
+        let item = options.get(index).unwrap();
+        match &item.value {
+            CommandOptionValue::SubCommandGroup(g)
+            | CommandOptionValue::SubCommand(g) => get_data(g),
+            _ => unreachable!()
+        }
+    } else {
+        options
+    }
+}
+
 impl<'a, D: 'a> DataIterator<'a, D> {
     /// Gets the first value which satisfies the given predicate.
     pub fn get<F>(&mut self, predicate: F) -> Option<&'a CommandDataOption>
@@ -63,25 +88,12 @@ impl<'a, D: 'a> DataIterator<'a, D> {
     }
 
     pub fn resolved(&mut self) -> Option<&mut CommandInteractionDataResolved> {
-        self.resolved.as_mut()
+        self.ctx.resolved.as_deref_mut()
     }
 
-    fn get_data(options: &Vec<CommandDataOption>) -> Vec<&CommandDataOption> {
-        if let Some(index) = options.iter().position(|item| {
-            item.value.kind() == CommandOptionType::SubCommand
-                || item.value.kind() == CommandOptionType::SubCommandGroup
-        })
-        {
-            let item = options.get(index).unwrap();
-            match &item.value {
-                CommandOptionValue::SubCommandGroup(g)
-                | CommandOptionValue::SubCommand(g) => Self::get_data(g),
-                _ => unreachable!()
-            }
-        } else {
-            options.iter()
-                .collect()
-        }
+    /// Gets the [context](ParseContext) given to the parsers.
+    pub fn context(&mut self) -> &mut ParseContext<'a, D> {
+        &mut self.ctx
     }
 }
 
@@ -90,26 +102,107 @@ where
     D: Send + Sync
 {
     pub async fn named_parse<T>(&mut self, name: &str) -> Result<T, ParseError>
+    where
+        T: Parse<D>
+    {
+        self.constrained_parse(name, &[]).await
+    }
+
+    /// Parses the argument with the given name, falling back to the provided default value if the
+    /// argument is not present.
+    pub async fn parse_or_else<T, F>(
+        &mut self,
+        name: &str,
+        constraints: &[ArgumentConstraint],
+        default: F
+    ) -> Result<T, ParseError>
+    where
+        T: Parse<D>,
+        F: FnOnce() -> T
+    {
+        if !self.src.iter().any(|option| option.name == name) {
+            return Ok(default());
+        }
+
+        self.constrained_parse(name, constraints).await
+    }
+
+    /// Parses a variadic argument, which is expanded into the `name1..nameN` options, being the
+    /// first `min` options required, collecting all the provided values in order.
+    pub async fn variadic_parse<T>(
+        &mut self,
+        name: &str,
+        min: usize,
+        max: usize,
+        constraints: &[ArgumentConstraint]
+    ) -> Result<Vec<T>, ParseError>
+    where
+        T: Parse<D>
+    {
+        let mut values = Vec::with_capacity(max);
+        for index in 1..=max {
+            let name = format!("{}{}", name, index);
+            if index > min && !self.src.iter().any(|option| option.name == name) {
+                continue;
+            }
+
+            values.push(self.constrained_parse(&name, constraints).await?);
+        }
+
+        Ok(values)
+    }
+
+    /// Parses the argument with the given name, checking it satisfies all the provided
+    /// [constraints](ArgumentConstraint) before.
+    pub async fn constrained_parse<T>(
+        &mut self,
+        name: &str,
+        constraints: &[ArgumentConstraint]
+    ) -> Result<T, ParseError>
     where
         T: Parse<D>
     {
         let value = self.get(|s| s.name == name);
         if value.is_none() && <T as Parse<D>>::required() {
-            Err(ParseError::StructureMismatch(format!("{} not found", name)).into())
-        } else {
-            Ok(T::parse(
-                self.http,
-                self.data,
-                value.map(|it| &it.value),
-                self.resolved())
-                .await
-                .map_err(|mut err| {
-                    if let ParseError::Parsing { argument_name, .. } = &mut err {
-                        *argument_name = name.to_string();
-                    }
-                    err
-                })?)
+            let error = self.ctx.translate("vesper.parse.missing", &[("name", &name)])
+                .unwrap_or_else(|| format!("{} not found", name));
+            return Err(ParseError::StructureMismatch(error));
+        }
+
+        if let Some(value) = value {
+            for constraint in constraints {
+                if let Err(error) = constraint.check(&value.value, self.ctx.resolved.as_deref()) {
+                    let (key, limit) = constraint.translation();
+                    return Err(ParseError::Parsing {
+                        argument_name: name.to_string(),
+                        required: T::required(),
+                        argument_type: format!("{:?}", T::kind()),
+                        error: self.ctx.translate(key, &[("limit", &limit)]).unwrap_or(error)
+                    });
+                }
+            }
+
+            if let Some(is_valid_choice) = self.choices {
+                if !is_valid_choice(name, &value.value) {
+                    return Err(ParseError::Parsing {
+                        argument_name: name.to_string(),
+                        required: T::required(),
+                        argument_type: format!("{:?}", T::kind()),
+                        error: self.ctx.translate("vesper.parse.choice", &[])
+                            .unwrap_or_else(|| String::from("The value is not one of the choices"))
+                    });
+                }
+            }
         }
+
+        T::parse_with_context(&mut self.ctx, value.map(|it| &it.value))
+            .await
+            .map_err(|mut err| {
+                if let ParseError::Parsing { argument_name, .. } = &mut err {
+                    *argument_name = name.to_string();
+                }
+                err
+            })
     }
 }
 
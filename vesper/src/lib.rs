@@ -3,19 +3,24 @@
 mod parse_impl;
 
 pub mod argument;
+pub mod autocomplete;
 pub mod builder;
 pub mod command;
 pub mod context;
+pub mod dedup;
 pub mod error;
 pub mod framework;
 pub mod group;
 pub mod hook;
+pub mod inject;
 pub mod iter;
 pub mod localizations;
 pub mod modal;
 pub mod parse;
 pub mod parsers;
 pub mod range;
+pub mod response;
+pub mod translator;
 pub mod wait;
 
 // Items used to extract generics from functions, not public API.
@@ -29,14 +34,21 @@ type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> +
 /// Useful exports to get started quickly
 pub mod prelude {
     pub use crate::{
+        argument::CommandArguments,
+        autocomplete::{Choice, IntoAutocompleteResponse},
         builder::{FrameworkBuilder, WrappedClient},
-        context::{AutocompleteContext, Focused, SlashContext},
+        context::{
+            AutocompleteContext, ComponentContext, ComponentWaiter, Focused, OwnedSlashContext,
+            ResponseState, SlashContext
+        },
         error::*,
         framework::{DefaultCommandResult, Framework},
+        inject::{Author, FromContext, GuildId, Locale, State},
         modal::*,
-        parse::{Parse, ParseError},
+        parse::{Parse, ParseContext, ParseError},
         parsers,
-        range::Range,
+        range::{FloatRange, Length, Range},
+        translator::Translator,
     };
     pub use async_trait::async_trait;
     pub use vesper_macros::*;
@@ -59,7 +71,7 @@ pub mod twilight_exports {
                 Interaction, InteractionData, InteractionType,
             },
         },
-        channel::{Message, message::{Component, component::{ActionRow, TextInput, TextInputStyle}}},
+        channel::{ChannelType, Message, message::{Component, component::{ActionRow, ComponentType, TextInput, TextInputStyle}}},
         gateway::payload::incoming::InteractionCreate,
         guild::Permissions,
         http::interaction::{
@@ -68,7 +80,7 @@ pub mod twilight_exports {
         id::{
             marker::{
                 ApplicationMarker, AttachmentMarker, ChannelMarker, GenericMarker, GuildMarker,
-                MessageMarker, RoleMarker, UserMarker,
+                InteractionMarker, MessageMarker, RoleMarker, UserMarker,
             },
             Id,
         },
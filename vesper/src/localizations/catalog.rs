@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::Path;
+use serde_json::Value;
+use crate::error::CatalogError;
+use crate::prelude::Framework;
+use crate::twilight_exports::{CommandOption, CommandOptionType, CommandType};
+
+/// The locales supported by discord.
+pub const LOCALES: [&str; 32] = [
+    "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl",
+    "no", "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+    "zh-CN", "ja", "zh-TW", "ko",
+];
+
+type Localizations = Option<HashMap<String, String>>;
+
+/// A set of translations grouped by locale, used to fill the localizations of the commands
+/// registered by the framework.
+///
+/// Entries are looked up by key, using the following format:
+///
+/// - `<command>.name` and `<command>.description` for commands and groups, user and message
+///   commands only use the name.
+/// - `<group>.<subcommand>.name` and `<group>.<subcommand>.description` for subcommands and
+///   subcommand groups, nesting as many levels as needed.
+/// - `<command>.args.<argument>.name` and `<command>.args.<argument>.description` for arguments.
+/// - `<command>.args.<argument>.choices.<choice name>` for argument choices.
+///
+/// Localizations given directly to commands and arguments take precedence over the catalog ones.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads all the `.json` files of the given directory, using their names as the locale of
+    /// their entries, so `es-ES.json` contains the spanish translations.
+    ///
+    /// Files can contain either flat keys like `{"ban.description": "..."}` or nested objects
+    /// like `{"ban": {"description": "..."}}`.
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let directory = directory.as_ref();
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| CatalogError::Io { path, source }
+        };
+
+        let mut this = Self::new();
+        for entry in fs::read_dir(directory).map_err(io_error(directory))? {
+            let path = entry.map_err(io_error(directory))?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+
+            let Some(locale) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let content = fs::read_to_string(&path).map_err(io_error(&path))?;
+            this.add_json(locale, &content)?;
+        }
+
+        Ok(this)
+    }
+
+    /// Adds the entries of the given json catalog to the given locale.
+    pub fn add_json(&mut self, locale: &str, json: &str) -> Result<(), CatalogError> {
+        let value = serde_json::from_str::<Value>(json)
+            .map_err(|source| CatalogError::Json { locale: locale.to_string(), source })?;
+
+        let mut entries = Vec::new();
+        flatten(String::new(), value, &mut entries)
+            .map_err(|key| CatalogError::InvalidValue { locale: locale.to_string(), key })?;
+
+        self.locales.entry(locale.to_string()).or_default().extend(entries);
+        Ok(())
+    }
+
+    /// Adds a single entry to the given locale.
+    pub fn insert(&mut self, locale: impl Into<String>, key: impl Into<String>, value: impl Into<String>) {
+        self.locales.entry(locale.into()).or_default().insert(key.into(), value.into());
+    }
+
+    /// Gets the value of the given key in the given locale.
+    pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+        self.locales.get(locale)?.get(key).map(String::as_str)
+    }
+
+    /// Returns an iterator over the locales present in the catalog.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.locales.keys().map(String::as_str)
+    }
+
+    /// Creates a [report](CatalogReport) of the keys the commands of the given framework use
+    /// but are missing from the catalog, and of the locales not supported by discord.
+    pub fn report<D, T, E>(&self, framework: &Framework<D, T, E>) -> CatalogReport {
+        let mut keys = Vec::new();
+
+        for cmd in framework.commands.values() {
+            if cmd.kind == CommandType::ChatInput {
+                let options = cmd.arguments.iter()
+                    .map(|argument| argument.as_option(framework, cmd))
+                    .collect::<Vec<_>>();
+                command_keys(cmd.name, &options, &mut keys);
+            } else {
+                // User and message commands only have a name.
+                keys.push(format!("{}.name", cmd.name));
+            }
+        }
+
+        for group in framework.groups.values() {
+            command_keys(group.name, &group.get_options(framework), &mut keys);
+        }
+
+        let mut report = CatalogReport::default();
+        for (locale, entries) in &self.locales {
+            if !LOCALES.contains(&locale.as_str()) {
+                report.unknown_locales.push(locale.clone());
+            }
+
+            let missing = keys.iter()
+                .filter(|key| !entries.contains_key(*key))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                report.missing_keys.insert(locale.clone(), missing);
+            }
+        }
+
+        report.unknown_locales.sort();
+        report
+    }
+
+    /// Fills the given localizations, and the ones of the given options, using the entries
+    /// found under the given path.
+    pub(crate) fn localize(
+        &self,
+        path: &str,
+        names: &mut Localizations,
+        descriptions: &mut Localizations,
+        options: &mut [CommandOption]
+    ) {
+        self.fill(names, &format!("{}.name", path));
+        self.fill(descriptions, &format!("{}.description", path));
+
+        for option in options {
+            self.localize_option(path, option);
+        }
+    }
+
+    fn localize_option(&self, parent: &str, option: &mut CommandOption) {
+        let path = option_path(parent, option);
+        self.fill(&mut option.name_localizations, &format!("{}.name", path));
+        self.fill(&mut option.description_localizations, &format!("{}.description", path));
+
+        for choice in option.choices.iter_mut().flatten() {
+            self.fill(&mut choice.name_localizations, &format!("{}.choices.{}", path, choice.name));
+        }
+
+        for option in option.options.iter_mut().flatten() {
+            self.localize_option(&path, option);
+        }
+    }
+
+    /// Adds the values of the given key to the localizations not already present.
+    fn fill(&self, localizations: &mut Localizations, key: &str) {
+        for (locale, entries) in &self.locales {
+            if let Some(value) = entries.get(key) {
+                localizations.get_or_insert_with(HashMap::new)
+                    .entry(locale.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
+}
+
+/// The problems found in a [catalog](Catalog), meant to be checked in CI.
+#[derive(Debug, Clone, Default)]
+pub struct CatalogReport {
+    /// The keys used by the framework missing from each locale.
+    pub missing_keys: BTreeMap<String, Vec<String>>,
+    /// The locales present in the catalog which are not supported by discord.
+    pub unknown_locales: Vec<String>,
+}
+
+impl CatalogReport {
+    /// Returns whether the report didn't find any problem.
+    pub fn is_empty(&self) -> bool {
+        self.missing_keys.is_empty() && self.unknown_locales.is_empty()
+    }
+}
+
+impl Display for CatalogReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for locale in &self.unknown_locales {
+            writeln!(f, "Unknown locale `{}`", locale)?;
+        }
+
+        for (locale, keys) in &self.missing_keys {
+            for key in keys {
+                writeln!(f, "Missing key `{}` in locale `{}`", key, locale)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets the path of the given option, nesting subcommands directly and arguments under `args`.
+fn option_path(parent: &str, option: &CommandOption) -> String {
+    match option.kind {
+        CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup => {
+            format!("{}.{}", parent, option.name)
+        },
+        _ => format!("{}.args.{}", parent, option.name)
+    }
+}
+
+/// Collects the keys used by a command having the given options.
+fn command_keys(path: &str, options: &[CommandOption], keys: &mut Vec<String>) {
+    keys.push(format!("{}.name", path));
+    keys.push(format!("{}.description", path));
+
+    for option in options {
+        let path = option_path(path, option);
+        command_keys(&path, option.options.as_deref().unwrap_or_default(), keys);
+
+        for choice in option.choices.iter().flatten() {
+            keys.push(format!("{}.choices.{}", path, choice.name));
+        }
+    }
+}
+
+/// Flattens the given json value into dot separated keys, returning the key of the first value
+/// which is not a string or an object.
+fn flatten(prefix: String, value: Value, entries: &mut Vec<(String, String)>) -> Result<(), String> {
+    match value {
+        Value::String(value) => entries.push((prefix, value)),
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(key, value, entries)?;
+            }
+        },
+        _ => return Err(prefix)
+    }
+
+    Ok(())
+}
@@ -2,6 +2,12 @@ use std::collections::HashMap;
 
 use crate::{prelude::Framework, command::Command, if_some};
 
+#[cfg(feature = "localizations")]
+mod catalog;
+
+#[cfg(feature = "localizations")]
+pub use catalog::{Catalog, CatalogReport, LOCALES};
+
 pub(crate) type LocalizationsProvider<D, T, E> = fn(&Framework<D, T, E>, &Command<D, T, E>) -> HashMap<String, String>;
 
 pub struct Localizations<D, T, E> {
@@ -1,26 +1,79 @@
-use std::future::{Future, IntoFuture};
-use std::marker::PhantomData;
+use std::fmt::Display;
+use std::future::Future;
 use std::pin::Pin;
-use std::task::{Context, Poll, ready};
+use std::str::FromStr;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use parking_lot::Mutex;
 use thiserror::Error;
 use tokio::sync::oneshot::error::RecvError;
 use twilight_model::channel::message::MessageFlags;
+use twilight_model::channel::message::component::{Button, ButtonStyle};
+use crate::BoxFuture;
 use crate::context::SlashContext;
-use crate::wait::InteractionWaiter;
-use crate::twilight_exports::{Interaction, InteractionClient, InteractionResponse, InteractionResponseType, InteractionResponseData};
+use crate::translator::Translations;
+use crate::wait::{new_pair, InteractionWaiter, WaiterWaker};
+use crate::twilight_exports::{
+    ActionRow, Component, Interaction, InteractionClient, InteractionData, InteractionResponse,
+    InteractionResponseType, InteractionResponseData, TextInput
+};
 use std::fmt::{Debug, Formatter};
-use twilight_http::response::marker::EmptyBody;
-use twilight_http::response::ResponseFuture;
 
+const TITLE_MAX_LENGTH: usize = 45;
+const LABEL_MAX_LENGTH: usize = 45;
+const PLACEHOLDER_MAX_LENGTH: usize = 100;
+const VALUE_MAX_LENGTH: usize = 4000;
+
+/// The times the user can fix an invalid modal by default.
+const DEFAULT_RETRIES: usize = 3;
+/// The time the user has to open an invalid modal again by default.
+const DEFAULT_RETRY_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 /// Errors that can be returned when awaiting modals.
 #[derive(Debug, Error)]
-#[error(transparent)]
 pub enum ModalError {
     /// An http error occurred.
+    #[error(transparent)]
     Http(#[from] twilight_http::Error),
     /// Something failed when using a [waiter](InteractionWaiter)
-    Waiter(#[from] RecvError)
+    #[error(transparent)]
+    Waiter(#[from] RecvError),
+    /// The submitted modal could not be parsed or was not valid.
+    #[error(transparent)]
+    Parse(#[from] ModalParseError),
+    /// The user did not open the modal again in time after an invalid submission.
+    #[error("The modal was not fixed in time")]
+    Timeout
+}
+
+/// Errors that can be returned when parsing a submitted [modal](Modal).
+#[derive(Debug, Error)]
+pub enum ModalParseError {
+    /// The modal contained a field not declared by the modal.
+    #[error("Unrecognized field `{0}`")]
+    UnknownField(String),
+    /// A required field had no value.
+    #[error("Missing value for field `{0}`")]
+    MissingField(String),
+    /// The value of a field failed to parse into the type of the field.
+    #[error("Failed to parse field `{field}`: {error}")]
+    InvalidField {
+        /// The name of the field.
+        field: String,
+        /// The error message as a string.
+        error: String
+    },
+    /// The modal, or one of its fields, did not pass its validation.
+    #[error("{error}")]
+    Invalid {
+        /// The name of the field, `None` if the whole modal was invalid.
+        field: Option<String>,
+        /// The error message as a string.
+        error: String
+    },
+    /// The interaction was not a modal submission.
+    #[error("The interaction is not a modal submission")]
+    NotAModal
 }
 
 /// The outcome of `.await`ing a [WaitModal](WaitModal).
@@ -60,39 +113,69 @@ impl<S> std::ops::DerefMut for ModalOutcome<S> {
 ///
 /// If the waiter is never awaited, the user won't be able to submit the modal, and will have to
 /// close it without submitting.
+///
+/// When the submitted modal fails to parse or to [validate](Modal::validate), the user is sent an
+/// ephemeral message containing the error and a button to open the modal again, pre-filled with
+/// the previous input. This is repeated up to [the given amount of times](Self::retries), updating
+/// the same message, and if the button is not clicked before [the timeout](Self::retry_timeout),
+/// the button is removed and the waiter returns [ModalError::Timeout].
 #[must_use = "Modals cannot be submitted if the waiter is not awaited"]
 pub struct WaitModal<'ctx, S> {
-    pub(crate) waiter: Option<InteractionWaiter>,
-    pub(crate) interaction: Option<Interaction>,
-    pub(crate) http_client: &'ctx InteractionClient<'ctx>,
-    pub(crate) flags: Option<MessageFlags>,
-    pub(crate) response_type: InteractionResponseType,
-    pub(crate) acknowledge: Option<ResponseFuture<EmptyBody>>,
-    pub(crate) parse_fn: fn(&mut Interaction) -> S,
-    pub(crate) _marker: PhantomData<S>,
+    pub(crate) prompt: Option<ModalPrompt<'ctx, S>>,
+    pub(crate) future: Option<BoxFuture<'ctx, Result<ModalOutcome<S>, ModalError>>>,
+}
+
+/// The state needed to wait for a modal, moved to the future of a [WaitModal] when it is
+/// first polled.
+pub(crate) struct ModalPrompt<'ctx, S> {
+    waiter: Option<InteractionWaiter>,
+    http_client: &'ctx InteractionClient<'ctx>,
+    waiters: &'ctx Mutex<Vec<WaiterWaker>>,
+    modal: InteractionResponse,
+    flags: Option<MessageFlags>,
+    response_type: InteractionResponseType,
+    retries: usize,
+    retry_timeout: Duration,
+    translations: &'ctx Translations,
+    locales: [Option<&'ctx str>; 2],
+    parse_fn: fn(&mut Interaction) -> Result<S, ModalParseError>,
 }
 
 impl<'ctx, S> WaitModal<'ctx, S> {
     pub(crate) fn new(
         waiter: InteractionWaiter,
         http_client: &'ctx InteractionClient<'ctx>,
-        parse_fn: fn(&mut Interaction) -> S,
+        waiters: &'ctx Mutex<Vec<WaiterWaker>>,
+        modal: InteractionResponse,
+        translations: &'ctx Translations,
+        locales: [Option<&'ctx str>; 2],
+        parse_fn: fn(&mut Interaction) -> Result<S, ModalParseError>,
     ) -> WaitModal<'ctx, S>
     {
         Self {
-            waiter: Some(waiter),
-            interaction: None,
-            http_client,
-            flags: None,
-            response_type: InteractionResponseType::DeferredUpdateMessage,
-            acknowledge: None,
-            parse_fn,
-            _marker: PhantomData,
+            prompt: Some(ModalPrompt {
+                waiter: Some(waiter),
+                http_client,
+                waiters,
+                modal,
+                flags: None,
+                response_type: InteractionResponseType::DeferredUpdateMessage,
+                retries: DEFAULT_RETRIES,
+                retry_timeout: DEFAULT_RETRY_TIMEOUT,
+                translations,
+                locales,
+                parse_fn,
+            }),
+            future: None,
         }
     }
 
+    fn prompt(&mut self) -> &mut ModalPrompt<'ctx, S> {
+        self.prompt.as_mut().expect("WaitModal modified after being polled")
+    }
+
     pub fn set_flags(mut self, flags: MessageFlags) -> Self {
-        self.flags = Some(flags);
+        self.prompt().flags = Some(flags);
         self
     }
 
@@ -101,74 +184,423 @@ impl<'ctx, S> WaitModal<'ctx, S> {
     }
 
     pub fn defer_response(mut self) -> Self {
-        self.response_type = InteractionResponseType::DeferredChannelMessageWithSource;
+        self.prompt().response_type = InteractionResponseType::DeferredChannelMessageWithSource;
+        self
+    }
+
+    /// Sets how many times the user can fix an invalid submission before the error is returned,
+    /// 3 by default. Setting it to 0 returns the error as soon as an invalid modal is submitted.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.prompt().retries = retries;
         self
     }
 
+    /// Sets how long the user has to click the button that opens the modal again after an
+    /// invalid submission, 5 minutes by default.
+    pub fn retry_timeout(mut self, timeout: Duration) -> Self {
+        self.prompt().retry_timeout = timeout;
+        self
+    }
+}
+
+impl<'ctx, S: Send + 'ctx> ModalPrompt<'ctx, S> {
+    async fn wait(mut self) -> Result<ModalOutcome<S>, ModalError> {
+        let modal_id = self.modal.data.as_ref()
+            .and_then(|data| data.custom_id.clone())
+            .unwrap_or_default();
+        let retry_id = format!("{}:retry", modal_id);
+
+        let mut waiter = self.waiter.take().unwrap();
+        let mut retries = self.retries;
+        let mut retried = false;
+        // The token of the interaction answered with the error message, used to update it once
+        // a valid modal is submitted.
+        let mut error_token = None;
+
+        loop {
+            let mut interaction = waiter.await?;
+            let values = submitted_values(&interaction);
+
+            let error = match (self.parse_fn)(&mut interaction) {
+                Ok(inner) => {
+                    self.acknowledge(&interaction, error_token.as_deref()).await?;
+                    return Ok(ModalOutcome {
+                        inner,
+                        interaction
+                    });
+                },
+                Err(error) => error
+            };
+
+            if retries == 0 {
+                self.respond_error(&interaction, &error, None, retried).await?;
+                return Err(error.into());
+            }
+            retries -= 1;
+
+            let button = self.waiter(&retry_id, |interaction, id| {
+                let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+                    return false;
+                };
+                data.custom_id == id
+            });
+            self.respond_error(&interaction, &error, Some(&retry_id), retried).await?;
+            if !retried {
+                error_token = Some(interaction.token.clone());
+            }
+
+            let button = match tokio::time::timeout(self.retry_timeout, button).await {
+                Ok(button) => button?,
+                Err(_) => {
+                    // The button waiter has been dropped, so its waker can't be woken anymore.
+                    self.waiters.lock().retain(|waker| !waker.sender.is_closed());
+                    self.remove_retry(&interaction).await;
+                    return Err(ModalError::Timeout);
+                }
+            };
+
+            waiter = self.waiter(&modal_id, |interaction, id| {
+                let Some(InteractionData::ModalSubmit(data)) = &interaction.data else {
+                    return false;
+                };
+                data.custom_id == id
+            });
+
+            let mut modal = self.modal.clone();
+            prefill(&mut modal, values);
+            self.http_client.create_response(button.id, &button.token, &modal).await?;
+            retried = true;
+        }
+    }
+
+    /// Registers a waiter for the interaction satisfying the given predicate, which receives the
+    /// given custom id.
+    fn waiter<F>(&self, id: &str, predicate: F) -> InteractionWaiter
+    where
+        F: Fn(&Interaction, &str) -> bool + Send + 'static
+    {
+        let id = id.to_string();
+        let (waker, waiter) = new_pair(move |interaction| predicate(interaction, &id));
+        self.waiters.lock().push(waker);
+        waiter
+    }
+
+    /// Translates the given key into the locale of the user who submitted the modal.
+    fn translate(&self, key: &str, args: &[(&str, &dyn Display)]) -> Option<String> {
+        self.translations.translate(self.locales, key, args)
+    }
+
+    /// Gets the message shown to the user for the given error, translated if possible.
+    ///
+    /// Validation errors are shown as returned by the validator.
+    fn error_message(&self, error: &ModalParseError) -> String {
+        let translated = match error {
+            ModalParseError::UnknownField(field) =>
+                self.translate("vesper.modal.unknown_field", &[("field", field)]),
+            ModalParseError::MissingField(field) =>
+                self.translate("vesper.modal.missing_field", &[("field", field)]),
+            ModalParseError::InvalidField { field, error } =>
+                self.translate("vesper.modal.invalid_field", &[("field", field), ("error", error)]),
+            ModalParseError::NotAModal => self.translate("vesper.modal.not_a_modal", &[]),
+            ModalParseError::Invalid { .. } => None
+        };
+
+        translated.unwrap_or_else(|| error.to_string())
+    }
+
+    /// Acknowledges the valid submission, replacing the content of the error message and removing
+    /// its retry button if the modal was opened from it.
+    ///
+    /// The error message is updated by the response itself when it only updates the message,
+    /// otherwise it is edited using the token of the interaction it was sent to.
+    async fn acknowledge(
+        &self,
+        interaction: &Interaction,
+        error_token: Option<&str>
+    ) -> Result<(), ModalError> {
+        let submitted = self.translate("vesper.modal.submitted", &[])
+            .unwrap_or_else(|| String::from("The modal has been submitted"));
+        let updates_error = self.response_type == InteractionResponseType::DeferredUpdateMessage;
+
+        let response = if error_token.is_some() && updates_error {
+            InteractionResponse {
+                kind: InteractionResponseType::UpdateMessage,
+                data: Some(InteractionResponseData {
+                    content: Some(submitted.clone()),
+                    components: Some(Vec::new()),
+                    flags: self.flags,
+                    ..Default::default()
+                })
+            }
+        } else {
+            InteractionResponse {
+                kind: self.response_type,
+                data: self.flags.map(|flags| InteractionResponseData {
+                    flags: Some(flags),
+                    ..Default::default()
+                })
+            }
+        };
+
+        self.http_client.create_response(interaction.id, &interaction.token, &response).await?;
+
+        if let Some(token) = error_token.filter(|_| !updates_error) {
+            let request = self.http_client.update_response(token)
+                .content(Some(&submitted))
+                .and_then(|request| request.components(Some(&[])));
+            if let Ok(request) = request {
+                request.await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Responds the invalid submission with an ephemeral message containing the error, and a
+    /// button to open the modal again if a custom id is provided.
+    ///
+    /// If the modal was opened from the button of a previous error message, that message is
+    /// replaced instead, so only the latest button can be clicked.
+    async fn respond_error(
+        &self,
+        interaction: &Interaction,
+        error: &ModalParseError,
+        retry_id: Option<&str>,
+        retried: bool
+    ) -> Result<(), ModalError> {
+        let components = retry_id.map(|id| vec![Component::ActionRow(ActionRow {
+            components: vec![Component::Button(Button {
+                custom_id: Some(id.to_string()),
+                disabled: false,
+                emoji: None,
+                label: Some(self.translate("vesper.modal.retry", &[])
+                    .unwrap_or_else(|| String::from("Fix it"))),
+                style: ButtonStyle::Primary,
+                url: None,
+            })]
+        })]).unwrap_or_default();
+
+        let (kind, flags) = if retried {
+            (InteractionResponseType::UpdateMessage, None)
+        } else {
+            (InteractionResponseType::ChannelMessageWithSource, Some(MessageFlags::EPHEMERAL))
+        };
 
+        let response = InteractionResponse {
+            kind,
+            data: Some(InteractionResponseData {
+                content: Some(self.error_message(error)),
+                components: Some(components),
+                flags,
+                ..Default::default()
+            })
+        };
+
+        self.http_client.create_response(interaction.id, &interaction.token, &response).await?;
+        Ok(())
+    }
+
+    /// Removes the retry button from the error message sent as response to the given
+    /// interaction, ignoring any error since the waiter already failed.
+    async fn remove_retry(&self, interaction: &Interaction) {
+        if let Ok(request) = self.http_client.update_response(&interaction.token).components(Some(&[])) {
+            let _ = request.await;
+        }
+    }
 }
 
-impl<'ctx, S> Future for WaitModal<'ctx, S> {
+impl<'ctx, S: Send + 'ctx> Future for WaitModal<'ctx, S> {
     type Output = Result<ModalOutcome<S>, ModalError>;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.future.is_none() {
+            let prompt = self.prompt.take().expect("WaitModal polled after completion");
+            self.future = Some(Box::pin(prompt.wait()));
+        }
+
+        self.future.as_mut().unwrap().as_mut().poll(cx)
+    }
+}
+
+/// Gets the values submitted to each field of the modal.
+fn submitted_values(interaction: &Interaction) -> Vec<(String, String)> {
+    let Some(InteractionData::ModalSubmit(data)) = &interaction.data else {
+        return Vec::new();
+    };
+
+    data.components.iter()
+        .flat_map(|row| &row.components)
+        .filter_map(|component| Some((component.custom_id.clone(), component.value.clone()?)))
+        .collect()
+}
 
-        if let Some(waiter) = this.waiter.as_mut() {
-            let interaction = ready!(Pin::new(waiter).poll(cx))
-                .map_err(ModalError::Waiter)?;
+/// Pre-fills the fields of the modal with the given values.
+fn prefill(modal: &mut InteractionResponse, values: Vec<(String, String)>) {
+    let inputs = modal.data.as_mut()
+        .and_then(|data| data.components.as_mut())
+        .into_iter()
+        .flatten()
+        .flat_map(|row| match row {
+            Component::ActionRow(row) => row.components.as_mut_slice(),
+            _ => &mut []
+        });
 
-            this.waiter = None;
-            this.interaction = Some(interaction);
+    for component in inputs {
+        if let Component::TextInput(input) = component {
+            if let Some((_, value)) = values.iter().find(|(id, _)| *id == input.custom_id) {
+                input.value = Some(value.clone()).filter(|value| !value.is_empty());
+            }
         }
+    }
+}
 
-        if this.acknowledge.is_none() {
-            let interaction = this.interaction.as_ref().unwrap();
-
-            let response = InteractionResponse {
-                kind: this.response_type,
-                data: if this.flags.is_none() {
-                    None
-                } else {
-                    Some(InteractionResponseData {
-                        flags: this.flags,
-                        ..Default::default()
-                    })
-                }  
-            };
+/// A builder used to modify a [modal](Modal) when it is sent, obtained using
+/// [SlashContext::create_modal_with](SlashContext::create_modal_with).
+///
+/// Fields are referenced by the name of the struct field they were declared in, and texts longer
+/// than the ones allowed by discord are truncated.
+pub struct ModalBuilder {
+    response: InteractionResponse,
+    locale: Option<String>,
+}
+
+impl ModalBuilder {
+    pub(crate) fn new(response: InteractionResponse, locale: Option<String>) -> Self {
+        Self {
+            response,
+            locale
+        }
+    }
 
-            let response = this.http_client.create_response(
-                interaction.id,
-                &interaction.token,
-                &response
-            );
+    /// Gets the locale of the user the modal will be shown to.
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
 
-            this.acknowledge = Some(response.into_future());
+    /// Sets the title of the modal.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        if let Some(data) = self.response.data.as_mut() {
+            data.title = Some(truncate(title.into(), TITLE_MAX_LENGTH));
         }
+        self
+    }
 
-        ready!(Pin::new(this.acknowledge.as_mut().unwrap()).poll(cx))
-            .map_err(ModalError::Http)?;
+    /// Sets the label of the given field.
+    pub fn label(self, field: &str, label: impl Into<String>) -> Self {
+        let label = truncate(label.into(), LABEL_MAX_LENGTH);
+        self.modify(field, |input| input.label = label)
+    }
+
+    /// Sets the placeholder of the given field.
+    pub fn placeholder(self, field: &str, placeholder: impl Into<String>) -> Self {
+        let placeholder = truncate(placeholder.into(), PLACEHOLDER_MAX_LENGTH);
+        self.modify(field, |input| input.placeholder = Some(placeholder))
+    }
+
+    /// Pre-fills the given field with a value, which the user can edit before submitting.
+    ///
+    /// The value is truncated to the maximum length of the field when it has one.
+    pub fn value(self, field: &str, value: impl Into<String>) -> Self {
+        let value = value.into();
+        self.modify(field, |input| {
+            let max = input.max_length
+                .map_or(VALUE_MAX_LENGTH, |max| VALUE_MAX_LENGTH.min(max as usize));
+            input.value = Some(truncate(value, max));
+        })
+    }
+
+    /// Modifies the text input of the given field, doing nothing if the modal has no such field.
+    fn modify<F>(mut self, field: &str, fun: F) -> Self
+    where
+        F: FnOnce(&mut TextInput)
+    {
+        let input = self.response.data.as_mut()
+            .and_then(|data| data.components.as_mut())
+            .into_iter()
+            .flatten()
+            .flat_map(|row| match row {
+                Component::ActionRow(row) => row.components.as_mut_slice(),
+                _ => &mut []
+            })
+            .find_map(|component| match component {
+                Component::TextInput(input) if input.custom_id == field => Some(input),
+                _ => None
+            });
+
+        if let Some(input) = input {
+            fun(input);
+        }
+        self
+    }
 
-        let mut interaction = this.interaction.take().unwrap();
+    pub(crate) fn build(self) -> InteractionResponse {
+        self.response
+    }
+}
 
-        Poll::Ready(Ok(ModalOutcome {
-            inner: (this.parse_fn)(&mut interaction),
-            interaction
-        }))
+fn truncate(mut text: String, max: usize) -> String {
+    if let Some((index, _)) = text.char_indices().nth(max) {
+        text.truncate(index);
     }
+
+    text
 }
 
 /// Trait used to define modals that can be sent to discord and parsed by the framework.
 ///
 /// This trait is normally implemented using the derive macro, refer to it to see full
 /// documentation about its usage and attributes.
-pub trait Modal<D> {
+pub trait Modal<D>: Sized {
     /// Creates the modal, returning the response needed to send it to discord.
     ///
     /// The framework provides as a custom id the interaction id converted to a string, this custom
     /// id must be used as the response custom id in order for the framework to retrieve the modal
-    /// data.
+    /// data. Each text input must use the name of its field as custom id, so the modal can be
+    /// modified using a [builder](ModalBuilder).
     fn create(ctx: &SlashContext<'_, D>, custom_id: String) -> InteractionResponse;
-    /// Parses the provided interaction into the modal;
-    fn parse(interaction: &mut Interaction) -> Self;
+    /// Parses the provided interaction into the modal, returning an error naming the field that
+    /// failed to parse.
+    fn parse(interaction: &mut Interaction) -> Result<Self, ModalParseError>;
+    /// Checks the parsed modal is valid, returning the reason why it isn't otherwise. Invalid
+    /// modals can be fixed by the user if the [waiter](WaitModal) allows retries.
+    fn validate(&self) -> Result<(), ModalParseError> {
+        Ok(())
+    }
+}
+
+/// Parses and validates the provided interaction into the given modal.
+pub(crate) fn parse_modal<D, M: Modal<D>>(interaction: &mut Interaction) -> Result<M, ModalParseError> {
+    let modal = M::parse(interaction)?;
+    modal.validate()?;
+    Ok(modal)
+}
+
+/// Parses the value of a required modal field, used by the derive macro.
+#[doc(hidden)]
+pub fn parse_field<T>(field: &str, value: Option<String>) -> Result<T, ModalParseError>
+where
+    T: FromStr,
+    T::Err: Display
+{
+    let value = value.filter(|value| !value.is_empty())
+        .ok_or_else(|| ModalParseError::MissingField(field.to_string()))?;
+
+    value.parse().map_err(|error: T::Err| ModalParseError::InvalidField {
+        field: field.to_string(),
+        error: error.to_string()
+    })
+}
+
+/// Parses the value of an optional modal field, being empty values treated as missing, used by
+/// the derive macro.
+#[doc(hidden)]
+pub fn parse_optional_field<T>(field: &str, value: Option<String>) -> Result<Option<T>, ModalParseError>
+where
+    T: FromStr,
+    T::Err: Display
+{
+    match value {
+        Some(value) if !value.is_empty() => parse_field(field, Some(value)).map(Some),
+        _ => Ok(None)
+    }
 }
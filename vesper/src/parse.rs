@@ -1,17 +1,147 @@
-use crate::{builder::WrappedClient, twilight_exports::*};
+use crate::{builder::WrappedClient, hook::AutocompleteHook, iter::get_data, translator::Translations, twilight_exports::*};
 use async_trait::async_trait;
 use std::error::Error;
+use std::fmt::Display;
+use twilight_model::user::User;
+
+/// Everything a [parser](Parse) has access to while parsing an argument.
+pub struct ParseContext<'a, T> {
+    /// The http client used by the framework.
+    pub http_client: &'a WrappedClient,
+    /// The data shared across the framework.
+    pub data: &'a T,
+    /// The id of the guild the command was executed in.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// The id of the channel the command was executed in.
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// The user who executed the command.
+    pub user: Option<&'a User>,
+    /// The locale selected by the user who executed the command.
+    pub locale: Option<&'a str>,
+    /// The preferred locale of the guild the command was executed in.
+    pub guild_locale: Option<&'a str>,
+    /// The permissions the application has in the channel the command was executed in.
+    pub app_permissions: Option<Permissions>,
+    /// The raw values of all the options provided to the command.
+    pub options: &'a [CommandDataOption],
+    /// The resolved data of the interaction, containing the users, roles, channels and
+    /// attachments referenced by the options.
+    pub resolved: Option<&'a mut CommandInteractionDataResolved>,
+    /// The translator of the framework, missing when the context is created manually.
+    pub(crate) translations: Option<&'a Translations>,
+}
+
+impl<'a, T> ParseContext<'a, T> {
+    /// Creates a context containing only the given items, used to call the legacy
+    /// [parse](Parse::parse) function.
+    ///
+    /// The user, guild, locales and permissions of the context are left as `None`, so parsers
+    /// called through [parse](Parse::parse) can't access them.
+    pub fn new(
+        http_client: &'a WrappedClient,
+        data: &'a T,
+        resolved: Option<&'a mut CommandInteractionDataResolved>
+    ) -> Self {
+        Self {
+            http_client,
+            data,
+            guild_id: None,
+            channel_id: None,
+            user: None,
+            locale: None,
+            guild_locale: None,
+            app_permissions: None,
+            options: &[],
+            resolved,
+            translations: None,
+        }
+    }
+
+    /// Creates a context for the given interaction, using the options provided to the executed
+    /// subcommand.
+    ///
+    /// Interactions other than application commands, such as the message components received by
+    /// a [component context](crate::context::ComponentContext), have no options to parse.
+    pub(crate) fn from_interaction(
+        http_client: &'a WrappedClient,
+        data: &'a T,
+        translations: Option<&'a Translations>,
+        interaction: &'a mut Interaction
+    ) -> Self {
+        let (options, resolved) = match interaction.data.as_mut() {
+            Some(InteractionData::ApplicationCommand(command)) => {
+                (get_data(&command.options), command.resolved.as_mut())
+            },
+            _ => (&[][..], None)
+        };
+
+        let user = interaction.member.as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(interaction.user.as_ref());
+
+        Self {
+            http_client,
+            data,
+            guild_id: interaction.guild_id,
+            channel_id: interaction.channel.as_ref().map(|channel| channel.id),
+            user,
+            locale: interaction.locale.as_deref(),
+            guild_locale: interaction.guild_locale.as_deref(),
+            app_permissions: interaction.app_permissions,
+            options,
+            resolved,
+            translations
+        }
+    }
+
+    /// Translates the given key using the [translator](crate::translator::Translator) of the
+    /// framework, trying the user, guild and default locales in order.
+    ///
+    /// Returns `None` if no translation is found, so parsers can fall back to their own message.
+    pub fn translate(&self, key: &str, args: &[(&str, &dyn Display)]) -> Option<String> {
+        self.translations?.translate([self.locale, self.guild_locale], key, args)
+    }
+
+    /// Gets the raw value of the option with the given name.
+    pub fn option(&self, name: &str) -> Option<&'a CommandOptionValue> {
+        self.options.iter()
+            .find(|option| option.name == name)
+            .map(|option| &option.value)
+    }
+}
 
 /// The core trait of this framework, it is used to parse all command arguments
+///
+/// Implementors must provide at least one of [parse_with_context](Parse::parse_with_context) and
+/// the deprecated [parse](Parse::parse) function, as their default implementations call each
+/// other. Implementing `parse_with_context` is preferred, since `parse` only receives part of the
+/// [context](ParseContext), and calling `parse` creates one with [ParseContext::new], which
+/// leaves the user, guild, locales and permissions as `None`.
 #[async_trait]
-pub trait Parse<T: Send + Sync>: Sized {
+pub trait Parse<T: Send + Sync>: Sized + Send {
     /// Parses the option into the argument.
+    #[deprecated(note = "implement `parse_with_context` instead")]
     async fn parse(
-        _http_client: &WrappedClient,
-        _data: &T,
-        _value: Option<&CommandOptionValue>,
-        _resolved: Option<&mut CommandInteractionDataResolved>
-    ) -> Result<Self, ParseError>;
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_context(&mut ParseContext::new(http_client, data, resolved), value).await
+    }
+
+    /// Parses the option into the argument, having access to the whole
+    /// [parse context](ParseContext).
+    ///
+    /// By default this calls the deprecated [parse](Parse::parse) function, so parsers written
+    /// before this function existed keep working.
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        #[allow(deprecated)]
+        Self::parse(ctx.http_client, ctx.data, value, ctx.resolved.as_deref_mut()).await
+    }
 
     /// Returns the option type this argument has.
     fn kind() -> CommandOptionType;
@@ -27,6 +157,12 @@ pub trait Parse<T: Send + Sync>: Sized {
         None
     }
 
+    /// Sets the function used to autocomplete the argument when no other one is specified, used by
+    /// the derive macro for enums having more choices than the ones allowed by discord.
+    fn autocomplete() -> Option<AutocompleteHook<T>> {
+        None
+    }
+
     fn modify_option(_option: &mut CommandOption) {}
 }
 
@@ -35,6 +171,9 @@ pub trait Parse<T: Send + Sync>: Sized {
 pub enum ParseError {
     /// The command arguments does not match with the framework ones.
     StructureMismatch(String),
+    /// A value required by a [context extractor](crate::inject::FromContext) is not present in
+    /// the interaction.
+    MissingContext(String),
     /// An argument failed parsing.
     Parsing {
         /// The name of the argument that failed to parse.
@@ -54,6 +193,7 @@ impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::StructureMismatch(why) => write!(f, "Structure mismatch: {}", why),
+            Self::MissingContext(why) => write!(f, "Missing context: {}", why),
             Self::Parsing { argument_name, required, argument_type, error } => {
                 write!(f, "Failed to parse {}({}required {}): {}", argument_name, {
                     if !required {
@@ -1,32 +1,45 @@
+use std::fmt::Display;
+use twilight_model::application::interaction::application_command::InteractionMember;
 use twilight_model::channel::Attachment;
 use twilight_model::guild::Role;
 use twilight_model::user::User;
+use crate::hook::AutocompleteHook;
 use crate::prelude::*;
 use crate::twilight_exports::*;
 
 const NUMBER_MAX_VALUE: i64 = 9007199254740991;
 
-pub(crate) fn error(type_name: &str, required: bool, why: &str) -> ParseError {
+/// Creates the error returned when the parser `P` fails, whose message is
+/// [translated](ParseContext::translate) using the given key, falling back to the provided one.
+pub(crate) fn error<T, P>(
+    ctx: &ParseContext<'_, T>,
+    type_name: &str,
+    key: &str,
+    args: &[(&str, &dyn Display)],
+    why: &str
+) -> ParseError
+where
+    T: Send + Sync,
+    P: Parse<T>
+{
     ParseError::Parsing {
         argument_name: String::new(),
-        required,
+        required: P::required(),
         argument_type: type_name.to_string(),
-        error: why.to_string()
+        error: ctx.translate(key, args).unwrap_or_else(|| why.to_string())
     }
 }
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for String {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::String(s)) = value {
             return Ok(s.to_owned());
         }
-        Err(error("String", true, "String expected"))
+        Err(error::<_, Self>(ctx, "String", "vesper.parse.string", &[], "String expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -36,16 +49,14 @@ impl<T: Send + Sync> Parse<T> for String {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for i64 {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Integer(i)) = value {
             return Ok(*i);
         }
-        Err(error("i64", true, "Integer expected"))
+        Err(error::<_, Self>(ctx, "i64", "vesper.parse.integer", &[], "Integer expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -55,19 +66,17 @@ impl<T: Send + Sync> Parse<T> for i64 {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for u64 {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Integer(i)) = value {
             if *i < 0 {
-                return Err(error("u64", true, "Input out of range"))
+                return Err(error::<_, Self>(ctx, "u64", "vesper.parse.out_of_range", &[], "Input out of range"))
             }
             return Ok(*i as u64);
         }
-        Err(error("Integer", true, "Integer expected"))
+        Err(error::<_, Self>(ctx, "Integer", "vesper.parse.integer", &[], "Integer expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -82,16 +91,14 @@ impl<T: Send + Sync> Parse<T> for u64 {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for f64 {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Number(i)) = value {
             return Ok(*i);
         }
-        Err(error("f64", true, "Number expected"))
+        Err(error::<_, Self>(ctx, "f64", "vesper.parse.number", &[], "Number expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -107,19 +114,17 @@ impl<T: Send + Sync> Parse<T> for f64 {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for f32 {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Number(i)) = value {
             if *i > f32::MAX as f64 || *i < f32::MIN as f64 {
-                return Err(error("f32", true, "Input out of range"))
+                return Err(error::<_, Self>(ctx, "f32", "vesper.parse.out_of_range", &[], "Input out of range"))
             }
             return Ok(*i as f32);
         }
-        Err(error("f32", true, "Number expected"))
+        Err(error::<_, Self>(ctx, "f32", "vesper.parse.number", &[], "Number expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -135,16 +140,14 @@ impl<T: Send + Sync> Parse<T> for f32 {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for bool {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Boolean(i)) = value {
             return Ok(*i);
         }
-        Err(error("Boolean", true, "Boolean expected"))
+        Err(error::<_, Self>(ctx, "Boolean", "vesper.parse.boolean", &[], "Boolean expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -154,17 +157,15 @@ impl<T: Send + Sync> Parse<T> for bool {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<AttachmentMarker> {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Attachment(attachment)) = value {
             return Ok(*attachment);
         }
 
-        Err(error("Attachment id", true, "Attachment expected"))
+        Err(error::<_, Self>(ctx, "Attachment id", "vesper.parse.attachment", &[], "Attachment expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -174,17 +175,14 @@ impl<T: Send + Sync> Parse<T> for Id<AttachmentMarker> {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Attachment {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &T,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
-        let id = <Id<AttachmentMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
+        let id = <Id<AttachmentMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
 
-        resolved.map(|item| item.attachments.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("Attachment", true, "Attachment expected"))
+        ctx.resolved.as_mut().and_then(|item| item.attachments.remove(&id))
+            .ok_or_else(|| error::<_, Self>(ctx, "Attachment", "vesper.parse.attachment", &[], "Attachment expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -194,17 +192,15 @@ impl<T: Send + Sync> Parse<T> for Attachment {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<ChannelMarker> {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Channel(channel)) = value {
             return Ok(*channel);
         }
 
-        Err(error("Channel id", true, "Channel expected"))
+        Err(error::<_, Self>(ctx, "Channel id", "vesper.parse.channel", &[], "Channel expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -214,17 +210,15 @@ impl<T: Send + Sync> Parse<T> for Id<ChannelMarker> {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<UserMarker> {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::User(user)) = value {
             return Ok(*user);
         }
 
-        Err(error("User id", true, "User expected"))
+        Err(error::<_, Self>(ctx, "User id", "vesper.parse.user", &[], "User expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -234,17 +228,31 @@ impl<T: Send + Sync> Parse<T> for Id<UserMarker> {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for User {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &T,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let id = <Id<UserMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
+
+        ctx.resolved.as_mut().and_then(|items| items.users.remove(&id))
+            .ok_or_else(|| error::<_, Self>(ctx, "User", "vesper.parse.user", &[], "User expected"))
+    }
+
+    fn kind() -> CommandOptionType {
+        <Id<UserMarker> as Parse<T>>::kind()
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for InteractionMember {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
-        let id = <Id<UserMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
+        let id = <Id<UserMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
 
-        resolved.map(|items| items.users.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("User", true, "User expected"))
+        ctx.resolved.as_mut().and_then(|items| items.members.remove(&id))
+            .ok_or_else(|| error::<_, Self>(ctx, "Member", "vesper.parse.member", &[], "Member expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -254,17 +262,15 @@ impl<T: Send + Sync> Parse<T> for User {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<RoleMarker> {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Role(role)) = value {
             return Ok(*role);
         }
 
-        Err(error("Role id", true, "Role expected"))
+        Err(error::<_, Self>(ctx, "Role id", "vesper.parse.role", &[], "Role expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -274,17 +280,14 @@ impl<T: Send + Sync> Parse<T> for Id<RoleMarker> {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Role {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &T,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
-        let id = <Id<RoleMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
+        let id = <Id<RoleMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
 
-        resolved.map(|items| items.roles.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("Role", true, "Role expected"))
+        ctx.resolved.as_mut().and_then(|items| items.roles.remove(&id))
+            .ok_or_else(|| error::<_, Self>(ctx, "Role", "vesper.parse.role", &[], "Role expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -294,17 +297,15 @@ impl<T: Send + Sync> Parse<T> for Role {
 
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<GenericMarker> {
-    async fn parse(
-        _: &WrappedClient,
-        _: &T,
-        value: Option<&CommandOptionValue>,
-        _: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Mentionable(id)) = value {
             return Ok(*id);
         }
 
-        Err(error("Id", true, "Mentionable expected"))
+        Err(error::<_, Self>(ctx, "Id", "vesper.parse.mentionable", &[], "Mentionable expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -314,13 +315,11 @@ impl<T: Send + Sync> Parse<T> for Id<GenericMarker> {
 
 #[async_trait]
 impl<T: Parse<E>, E: Send + Sync> Parse<E> for Option<T> {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &E,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, E>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
-        match T::parse(http_client, data, value, resolved).await {
+        match T::parse_with_context(ctx, value).await {
             Ok(parsed) => Ok(Some(parsed)),
             Err(mut why) => {
                 if value.is_some() {
@@ -348,6 +347,10 @@ impl<T: Parse<E>, E: Send + Sync> Parse<E> for Option<T> {
         T::choices()
     }
 
+    fn autocomplete() -> Option<AutocompleteHook<E>> {
+        T::autocomplete()
+    }
+
     fn modify_option(option: &mut CommandOption) {
         T::modify_option(option)
     }
@@ -357,17 +360,15 @@ impl<T: Parse<E>, E: Send + Sync> Parse<E> for Option<T> {
 impl<T, E, C> Parse<C> for Result<T, E>
 where
     T: Parse<C>,
-    E: From<ParseError>,
+    E: From<ParseError> + Send,
     C: Send + Sync,
 {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &C,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, C>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
         // as we want to return the error if occurs, we'll map the error and always return Ok
-        Ok(T::parse(http_client, data, value, resolved).await.map_err(From::from))
+        Ok(T::parse_with_context(ctx, value).await.map_err(From::from))
     }
 
     fn kind() -> CommandOptionType {
@@ -382,6 +383,10 @@ where
         T::choices()
     }
 
+    fn autocomplete() -> Option<AutocompleteHook<C>> {
+        T::autocomplete()
+    }
+
     fn modify_option(option: &mut CommandOption) {
         T::modify_option(option)
     }
@@ -392,18 +397,18 @@ macro_rules! impl_derived_parse {
         $($(
             #[async_trait]
             impl<T: Send + Sync> Parse<T> for $derived {
-                async fn parse(
-                    http_client: &WrappedClient,
-                    data: &T,
-                    value: Option<&CommandOptionValue>,
-                    resolved: Option<&mut CommandInteractionDataResolved>
+                async fn parse_with_context(
+                    ctx: &mut ParseContext<'_, T>,
+                    value: Option<&CommandOptionValue>
                 ) -> Result<Self, ParseError> {
-                    let p = <$prim>::parse(http_client, data, value, resolved).await?;
+                    let p = <$prim>::parse_with_context(ctx, value).await?;
 
                     if p > <$derived>::MAX as $prim {
-                        Err(error(
+                        Err(error::<_, Self>(
+                            ctx,
                             stringify!($derived),
-                            true,
+                            "vesper.parse.out_of_range",
+                            &[],
                             concat!(
                                 "Failed to parse to ",
                                 stringify!($derived),
@@ -414,9 +419,11 @@ macro_rules! impl_derived_parse {
                             )
                         ))
                     } else if p < <$derived>::MIN as $prim {
-                        Err(error(
+                        Err(error::<_, Self>(
+                            ctx,
                             stringify!($derived),
-                            true,
+                            "vesper.parse.out_of_range",
+                            &[],
                             concat!(
                                 "Failed to parse to ",
                                 stringify!($derived),
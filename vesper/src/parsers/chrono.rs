@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use async_trait::async_trait;
+use ::chrono::{FixedOffset, NaiveDate};
+use twilight_model::application::command::CommandOptionType;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::datetime::{parse_datetime, OffsetSource, RawDateTime, Utc};
+
+/// A [chrono date](::chrono::DateTime) provided as a string, such as `2024-05-01 18:30`,
+/// `2024-05-01T18:30:00+02:00` or `in 2h`.
+///
+/// Dates without an explicit offset are interpreted using the offset given by the
+/// [offset source](OffsetSource), which defaults to [UTC](Utc).
+pub struct DateTime<S = Utc>(pub ::chrono::DateTime<FixedOffset>, PhantomData<fn() -> S>);
+
+impl<S> DateTime<S> {
+    /// Consumes the wrapper, returning the inner date.
+    pub fn into_inner(self) -> ::chrono::DateTime<FixedOffset> {
+        self.0
+    }
+}
+
+impl<S> Deref for DateTime<S> {
+    type Target = ::chrono::DateTime<FixedOffset>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S> DerefMut for DateTime<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T, S> Parse<T> for DateTime<S>
+where
+    T: Send + Sync,
+    S: OffsetSource<T>
+{
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+        let raw = parse_datetime(&input)
+            .map_err(|why| error::<_, Self>(ctx, "DateTime", "vesper.parse.datetime", &[], &why))?;
+
+        let default_offset = match raw {
+            RawDateTime::Absolute { offset: None, .. } => S::offset(ctx).await,
+            _ => 0
+        };
+
+        resolve(raw, default_offset)
+            .map(|date| Self(date, PhantomData))
+            .ok_or_else(|| error::<_, Self>(ctx, "DateTime", "vesper.parse.datetime", &[], "Invalid date"))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// Converts the given date into a chrono one, using the given offset when the date doesn't
+/// specify one, returning `None` if the date is not valid.
+fn resolve(raw: RawDateTime, default_offset: i32) -> Option<::chrono::DateTime<FixedOffset>> {
+    match raw {
+        RawDateTime::Relative(duration) => ::chrono::Duration::from_std(duration)
+            .ok()
+            .and_then(|duration| {
+                ::chrono::DateTime::<FixedOffset>::from(::chrono::Utc::now())
+                    .checked_add_signed(duration)
+            }),
+        RawDateTime::Absolute { year, month, day, hour, minute, second, offset } => {
+            FixedOffset::east_opt(offset.unwrap_or(default_offset)).and_then(|offset| {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)?
+                    .and_hms_opt(hour as u32, minute as u32, second as u32)?
+                    .and_local_timezone(offset)
+                    .single()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+    use ::chrono::Datelike;
+    use super::*;
+
+    fn absolute(year: i32, month: u8, day: u8, offset: Option<i32>) -> RawDateTime {
+        RawDateTime::Absolute { year, month, day, hour: 18, minute: 30, second: 15, offset }
+    }
+
+    fn rfc3339(raw: RawDateTime, default_offset: i32) -> Option<String> {
+        resolve(raw, default_offset).map(|date| date.to_rfc3339())
+    }
+
+    #[test]
+    fn resolves_offsets() {
+        assert_eq!(
+            rfc3339(absolute(2024, 5, 1, Some(7200)), 0),
+            Some("2024-05-01T18:30:15+02:00".to_string())
+        );
+        assert_eq!(
+            rfc3339(absolute(2024, 5, 1, None), -19_800),
+            Some("2024-05-01T18:30:15-05:30".to_string())
+        );
+        assert_eq!(
+            rfc3339(absolute(2024, 5, 1, Some(0)), 3600),
+            Some("2024-05-01T18:30:15+00:00".to_string())
+        );
+        assert_eq!(rfc3339(absolute(2024, 5, 1, None), 100_000), None);
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert_eq!(rfc3339(absolute(2023, 2, 29, None), 0), None);
+        assert_eq!(rfc3339(absolute(2024, 13, 1, None), 0), None);
+
+        let invalid_hour = RawDateTime::Absolute {
+            year: 2024, month: 5, day: 1, hour: 24, minute: 0, second: 0, offset: None
+        };
+        assert_eq!(resolve(invalid_hour, 0), None);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let max = ::chrono::NaiveDate::MAX;
+        let min = ::chrono::NaiveDate::MIN;
+
+        assert!(rfc3339(absolute(max.year(), 12, 31, Some(0)), 0).is_some());
+        assert!(rfc3339(absolute(max.year() + 1, 1, 1, Some(0)), 0).is_none());
+        assert!(rfc3339(absolute(min.year(), 1, 1, Some(0)), 0).is_some());
+        assert!(rfc3339(absolute(min.year() - 1, 12, 31, Some(0)), 0).is_none());
+        assert!(rfc3339(absolute(i32::MAX, 1, 1, Some(0)), 0).is_none());
+    }
+
+    #[test]
+    fn resolves_relative_dates() {
+        let now = ::chrono::Utc::now();
+        let date = resolve(RawDateTime::Relative(StdDuration::from_secs(3600)), 0).unwrap();
+
+        assert!(date.signed_duration_since(now) >= ::chrono::Duration::seconds(3600));
+        assert_eq!(resolve(RawDateTime::Relative(StdDuration::from_secs(u64::MAX)), 0), None);
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use async_trait::async_trait;
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::InvalidInput;
+
+const NAMED_COLORS: [(&str, u32); 17] = [
+    ("black", 0x000000),
+    ("white", 0xFFFFFF),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("red", 0xFF0000),
+    ("green", 0x00FF00),
+    ("blue", 0x0000FF),
+    ("yellow", 0xFFFF00),
+    ("orange", 0xFFA500),
+    ("purple", 0x800080),
+    ("pink", 0xFFC0CB),
+    ("cyan", 0x00FFFF),
+    ("magenta", 0xFF00FF),
+    ("brown", 0xA52A2A),
+    ("gold", 0xFFD700),
+    ("blurple", 0x5865F2),
+    ("fuchsia", 0xEB459E),
+];
+
+/// A color provided as a string, which can be an hex code like `#5865F2`, `0x5865F2` or `#FFF`,
+/// an `rgb(88, 101, 242)` function or a named color like `blurple`.
+///
+/// The inner value can be used directly as the color of an embed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u32);
+
+impl Color {
+    /// Gets the red component of the color.
+    pub fn r(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Gets the green component of the color.
+    pub fn g(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Gets the blue component of the color.
+    pub fn b(&self) -> u8 {
+        self.0 as u8
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self((r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    fn parse_hex(input: &str) -> Option<Self> {
+        let hex = input.strip_prefix('#').or_else(|| input.strip_prefix("0x"))?;
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        match hex.len() {
+            6 => u32::from_str_radix(hex, 16).ok().map(Self),
+            3 => {
+                let mut digits = hex.chars().map(|c| c.to_digit(16).unwrap() as u8 * 0x11);
+                Some(Self::from_rgb(digits.next()?, digits.next()?, digits.next()?))
+            },
+            _ => None
+        }
+    }
+
+    fn parse_rgb(input: &str) -> Option<Self> {
+        let inner = input.strip_prefix("rgb(")?.strip_suffix(')')?;
+        let mut components = inner.split(',').map(|c| c.trim().parse::<u8>());
+
+        let color = Self::from_rgb(
+            components.next()?.ok()?,
+            components.next()?.ok()?,
+            components.next()?.ok()?
+        );
+
+        if components.next().is_some() {
+            None
+        } else {
+            Some(color)
+        }
+    }
+
+    fn parse_named(input: &str) -> Option<Self> {
+        NAMED_COLORS.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(input))
+            .map(|(_, color)| Self(*color))
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "#{:06X}", self.0)
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim().to_lowercase();
+
+        Self::parse_hex(&input)
+            .or_else(|| Self::parse_rgb(&input))
+            .or_else(|| Self::parse_named(&input))
+            .ok_or_else(|| InvalidInput(String::from("Expected an hex color, rgb() or a color name")))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Color {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+
+        input.parse()
+            .map_err(|why: InvalidInput| error::<_, Self>(ctx, "Color", "vesper.parse.color", &[], &why.0))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.min_length = Some(3);
+        option.max_length = Some(32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(input: &str) -> Option<u32> {
+        input.parse::<Color>().ok().map(|color| color.0)
+    }
+
+    #[test]
+    fn parses_hex_prefixes() {
+        assert_eq!(color("#5865F2"), Some(0x5865F2));
+        assert_eq!(color("#5865f2"), Some(0x5865F2));
+        assert_eq!(color("0x5865F2"), Some(0x5865F2));
+        assert_eq!(color("0X5865F2"), Some(0x5865F2));
+        assert_eq!(color(" #000000 "), Some(0));
+        assert_eq!(color("5865F2"), None);
+        assert_eq!(color("##5865F2"), None);
+        assert_eq!(color("#0x5865F2"), None);
+        assert_eq!(color("0x#5865F2"), None);
+        assert_eq!(color("#"), None);
+        assert_eq!(color("0x"), None);
+    }
+
+    #[test]
+    fn parses_short_hex() {
+        assert_eq!(color("#FFF"), Some(0xFFFFFF));
+        assert_eq!(color("#a1c"), Some(0xAA11CC));
+        assert_eq!(color("0x08f"), Some(0x0088FF));
+        assert_eq!(color("#ffff"), None);
+        assert_eq!(color("#12345"), None);
+        assert_eq!(color("#1234567"), None);
+        assert_eq!(color("#ggg"), None);
+    }
+
+    #[test]
+    fn parses_rgb_and_names() {
+        assert_eq!(color("rgb(88, 101, 242)"), Some(0x5865F2));
+        assert_eq!(color("RGB(0,0,0)"), Some(0));
+        assert_eq!(color("rgb(256, 0, 0)"), None);
+        assert_eq!(color("rgb(1, 2)"), None);
+        assert_eq!(color("rgb(1, 2, 3, 4)"), None);
+        assert_eq!(color("rgb(-1, 2, 3)"), None);
+        assert_eq!(color("Blurple"), Some(0x5865F2));
+        assert_eq!(color("grey"), color("gray"));
+        assert_eq!(color("not a color"), None);
+    }
+
+    #[test]
+    fn rejects_non_ascii_input() {
+        assert_eq!(color("＃FFF"), None);
+        assert_eq!(color("#ＦＦＦ"), None);
+        assert_eq!(color("#ÿÿÿ"), None);
+        assert_eq!(color("rgb(１, 2, 3)"), None);
+    }
+
+    #[test]
+    fn formats_components() {
+        let color = Color(0x5865F2);
+
+        assert_eq!((color.r(), color.g(), color.b()), (88, 101, 242));
+        assert_eq!(color.to_string(), "#5865F2");
+        assert_eq!(Color(0xFF).to_string(), "#0000FF");
+    }
+}
@@ -0,0 +1,222 @@
+use std::str::FromStr;
+use std::time::Duration as StdDuration;
+use async_trait::async_trait;
+use crate::parse::ParseContext;
+use super::duration::parse_duration;
+
+/// Provides the timezone offset used to interpret the dates given by users that don't specify
+/// one explicitly.
+///
+/// This can be implemented to look up the timezone of the user executing the command, for
+/// example from a database accessible through the framework data.
+#[async_trait]
+pub trait OffsetSource<D: Send + Sync> {
+    /// Returns the offset from UTC of the user's timezone, in seconds.
+    async fn offset(ctx: &ParseContext<'_, D>) -> i32;
+}
+
+/// An [offset source](OffsetSource) interpreting all dates as UTC.
+pub struct Utc;
+
+#[async_trait]
+impl<D: Send + Sync> OffsetSource<D> for Utc {
+    async fn offset(_: &ParseContext<'_, D>) -> i32 {
+        0
+    }
+}
+
+/// A date provided by the user, before being converted into the chosen date-time library types.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RawDateTime {
+    /// A date relative to the current time, such as `in 2h`.
+    Relative(StdDuration),
+    /// An absolute date, such as `2024-05-01 18:30 +02:00`.
+    Absolute {
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        /// The offset given by the user, in seconds.
+        offset: Option<i32>,
+    },
+}
+
+/// Parses a date in one of the following formats:
+///
+/// - `in <duration>`, relative to the current time.
+/// - `YYYY-MM-DD`, at midnight.
+/// - `YYYY-MM-DD HH:MM[:SS]`, where the space can also be a `T`.
+///
+/// Absolute dates can be followed by an offset, which can be `Z`, `UTC`, `+HH`, `+HHMM` or
+/// `+HH:MM`.
+pub(crate) fn parse_datetime(input: &str) -> Result<RawDateTime, String> {
+    let input = input.trim();
+    if let Some(relative) = input.strip_prefix("in ") {
+        return parse_duration(relative).map(RawDateTime::Relative);
+    }
+
+    let date_end = input.find(|c: char| c == 'T' || c.is_whitespace()).unwrap_or(input.len());
+    let mut date = input[..date_end].split('-');
+    let (year, month, day) = match (date.next(), date.next(), date.next(), date.next()) {
+        (Some(year), Some(month), Some(day), None) => {
+            (number(year, "year")?, number(month, "month")?, number(day, "day")?)
+        },
+        _ => return Err("Expected a date like `YYYY-MM-DD` or `in <duration>`".to_string())
+    };
+
+    let rest = input[date_end..].trim_start_matches(|c: char| c == 'T' || c.is_whitespace());
+    let time_end = rest.find(|c: char| matches!(c, 'Z' | 'U' | '+' | '-') || c.is_whitespace())
+        .unwrap_or(rest.len());
+    let (hour, minute, second) = if time_end == 0 {
+        (0, 0, 0)
+    } else {
+        let mut time = rest[..time_end].split(':');
+        match (time.next(), time.next(), time.next(), time.next()) {
+            (Some(hour), Some(minute), second, None) => (
+                number(hour, "hour")?,
+                number(minute, "minute")?,
+                second.map(|second| number(second, "second")).transpose()?.unwrap_or(0)
+            ),
+            _ => return Err("Expected a time like `HH:MM` or `HH:MM:SS`".to_string())
+        }
+    };
+
+    Ok(RawDateTime::Absolute {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        offset: parse_offset(rest[time_end..].trim())?,
+    })
+}
+
+fn parse_offset(input: &str) -> Result<Option<i32>, String> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    if input == "Z" || input.eq_ignore_ascii_case("UTC") {
+        return Ok(Some(0));
+    }
+
+    let (sign, digits) = if let Some(digits) = input.strip_prefix('+') {
+        (1, digits)
+    } else if let Some(digits) = input.strip_prefix('-') {
+        (-1, digits)
+    } else {
+        return Err(format!("Invalid offset `{}`", input));
+    };
+
+    let digits = digits.replace(':', "");
+    if !digits.is_ascii() {
+        return Err(format!("Invalid offset `{}`", input));
+    }
+
+    let (hours, minutes) = match digits.len() {
+        2 => (number::<i32>(&digits, "offset")?, 0),
+        4 => (number::<i32>(&digits[..2], "offset")?, number::<i32>(&digits[2..], "offset")?),
+        _ => return Err(format!("Invalid offset `{}`", input))
+    };
+
+    if hours > 23 || minutes > 59 {
+        return Err(format!("Invalid offset `{}`", input));
+    }
+
+    Ok(Some(sign * (hours * 3600 + minutes * 60)))
+}
+
+fn number<N: FromStr>(input: &str, what: &str) -> Result<N, String> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid {} `{}`", what, input));
+    }
+
+    input.parse().map_err(|_| format!("Invalid {} `{}`", what, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8, offset: Option<i32>)
+        -> RawDateTime
+    {
+        RawDateTime::Absolute { year, month, day, hour, minute, second, offset }
+    }
+
+    fn offset(input: &str) -> Result<Option<i32>, String> {
+        match parse_datetime(input)? {
+            RawDateTime::Absolute { offset, .. } => Ok(offset),
+            RawDateTime::Relative(_) => panic!("`{}` parsed as a relative date", input)
+        }
+    }
+
+    #[test]
+    fn parses_relative_dates() {
+        assert_eq!(parse_datetime("in 2h"), Ok(RawDateTime::Relative(StdDuration::from_secs(7200))));
+        assert_eq!(
+            parse_datetime(" in 1d 30m "),
+            Ok(RawDateTime::Relative(StdDuration::from_secs(88_200)))
+        );
+        assert!(parse_datetime("in").is_err());
+        assert!(parse_datetime("in 5 parsecs").is_err());
+    }
+
+    #[test]
+    fn parses_absolute_dates() {
+        assert_eq!(parse_datetime("2024-05-01"), Ok(at(2024, 5, 1, 0, 0, 0, None)));
+        assert_eq!(parse_datetime("2024-05-01 18:30"), Ok(at(2024, 5, 1, 18, 30, 0, None)));
+        assert_eq!(parse_datetime("2024-05-01T18:30:15"), Ok(at(2024, 5, 1, 18, 30, 15, None)));
+        assert_eq!(parse_datetime("2024-05-01T18:30:15Z"), Ok(at(2024, 5, 1, 18, 30, 15, Some(0))));
+    }
+
+    #[test]
+    fn parses_offsets() {
+        assert_eq!(offset("2024-05-01 18:30 UTC"), Ok(Some(0)));
+        assert_eq!(offset("2024-05-01 18:30 utc"), Ok(Some(0)));
+        assert_eq!(offset("2024-05-01 18:30+02"), Ok(Some(7200)));
+        assert_eq!(offset("2024-05-01 18:30 +02:00"), Ok(Some(7200)));
+        assert_eq!(offset("2024-05-01T18:30:00-0530"), Ok(Some(-19_800)));
+        assert_eq!(offset("2024-05-01 -23:59"), Ok(Some(-86_340)));
+    }
+
+    #[test]
+    fn rejects_invalid_offsets() {
+        assert_eq!(parse_offset("+24:00"), Err("Invalid offset `+24:00`".to_string()));
+        assert_eq!(parse_offset("+02:60"), Err("Invalid offset `+02:60`".to_string()));
+        assert_eq!(parse_offset("+2"), Err("Invalid offset `+2`".to_string()));
+        assert_eq!(parse_offset("+02:000"), Err("Invalid offset `+02:000`".to_string()));
+        assert_eq!(parse_offset("CET"), Err("Invalid offset `CET`".to_string()));
+    }
+
+    #[test]
+    fn rejects_malformed_dates() {
+        assert!(parse_datetime("").is_err());
+        assert!(parse_datetime("2024/05/01").is_err());
+        assert!(parse_datetime("2024-05").is_err());
+        assert!(parse_datetime("2024-05-01-02").is_err());
+        assert!(parse_datetime("2024-05-01 18").is_err());
+        assert!(parse_datetime("2024-05-01 18:30:00:00").is_err());
+        assert!(parse_datetime("2024-05-01 18:").is_err());
+        assert!(parse_datetime("2024-5a-01").is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_numbers() {
+        assert_eq!(parse_datetime("99999999999-01-01"), Err("Invalid year `99999999999`".to_string()));
+        assert_eq!(parse_datetime("2024-256-01"), Err("Invalid month `256`".to_string()));
+        assert_eq!(parse_datetime("2024-05-01 300:00"), Err("Invalid hour `300`".to_string()));
+    }
+
+    #[test]
+    fn rejects_non_ascii_input() {
+        assert_eq!(parse_datetime("２０２４-05-01"), Err("Invalid year `２０２４`".to_string()));
+        assert_eq!(parse_datetime("2024-05-01 1８:30"), Err("Invalid hour `1８`".to_string()));
+        assert_eq!(parse_offset("+٠٢:٠٠"), Err("Invalid offset `+٠٢:٠٠`".to_string()));
+        assert!(parse_datetime("2024-05-01 18:30 ＋02:00").is_err());
+        assert!(parse_datetime("明日").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use std::time::Duration as StdDuration;
+use async_trait::async_trait;
+use twilight_model::application::command::CommandOptionType;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::InvalidInput;
+
+const UNITS: [(&[&str], u64); 5] = [
+    (&["s", "sec", "secs", "second", "seconds"], 1),
+    (&["m", "min", "mins", "minute", "minutes"], 60),
+    (&["h", "hr", "hrs", "hour", "hours"], 60 * 60),
+    (&["d", "day", "days"], 60 * 60 * 24),
+    (&["w", "week", "weeks"], 60 * 60 * 24 * 7),
+];
+
+/// A duration provided as a string, such as `1h30m`, `2d` or `1 week 3 days`, whose length in
+/// seconds must be between `MIN` and `MAX`, both inclusive.
+///
+/// Supported units are weeks (`w`), days (`d`), hours (`h`), minutes (`m`) and seconds (`s`),
+/// along with their long forms.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration<const MIN: u64 = 0, const MAX: u64 = { u64::MAX }>(pub StdDuration);
+
+impl<const MIN: u64, const MAX: u64> Deref for Duration<MIN, MAX> {
+    type Target = StdDuration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> DerefMut for Duration<MIN, MAX> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Duration<MIN, MAX> {
+    /// Parses the given input, checking it is between the allowed bounds.
+    pub(crate) fn parse_str(input: &str) -> Result<Self, String> {
+        let duration = parse_duration(input)?;
+        let secs = duration.as_secs();
+
+        if secs < MIN {
+            return Err(format!("Duration must be at least {}", format_secs(MIN)));
+        }
+
+        if secs > MAX {
+            return Err(format!("Duration must be at most {}", format_secs(MAX)));
+        }
+
+        Ok(Self(duration))
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> FromStr for Duration<MIN, MAX> {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(input).map_err(InvalidInput)
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, const MIN: u64, const MAX: u64> Parse<T> for Duration<MIN, MAX> {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+
+        Self::parse_str(&input)
+            .map_err(|why| error::<_, Self>(
+                ctx,
+                &format!("Duration<{}, {}>", MIN, MAX),
+                "vesper.parse.duration",
+                &[],
+                &why
+            ))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Debug for Duration<MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Duration<{}, {}>({:?})", MIN, MAX, self.0)
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Display for Duration<MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&format_secs(self.0.as_secs()))
+    }
+}
+
+/// Parses a humantime-like duration, with seconds precision.
+pub(crate) fn parse_duration(input: &str) -> Result<StdDuration, String> {
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err("Expected a duration".to_string());
+    }
+
+    let mut total: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(format!("Expected a number at `{}`", rest));
+        }
+
+        let amount = rest[..digits].parse::<u64>()
+            .map_err(|_| "Duration is too long".to_string())?;
+        rest = rest[digits..].trim_start();
+
+        let letters = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+        let unit = rest[..letters].to_lowercase();
+        if unit.is_empty() {
+            return Err(format!("Missing unit after `{}`", amount));
+        }
+
+        let multiplier = UNITS.iter()
+            .find(|(names, _)| names.contains(&unit.as_str()))
+            .map(|(_, multiplier)| *multiplier)
+            .ok_or_else(|| format!("Unknown unit `{}`", unit))?;
+
+        total = amount.checked_mul(multiplier)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(|| "Duration is too long".to_string())?;
+
+        rest = rest[letters..].trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+    }
+
+    Ok(StdDuration::from_secs(total))
+}
+
+/// Formats the given amount of seconds using the largest possible units, like `1d 2h 30m`.
+fn format_secs(mut secs: u64) -> String {
+    if secs == 0 {
+        return "0s".to_string();
+    }
+
+    let mut parts = Vec::new();
+    for (names, multiplier) in UNITS.iter().rev() {
+        if secs >= *multiplier {
+            parts.push(format!("{}{}", secs / multiplier, names[0]));
+            secs %= multiplier;
+        }
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(input: &str) -> Result<u64, String> {
+        parse_duration(input).map(|duration| duration.as_secs())
+    }
+
+    #[test]
+    fn parses_units() {
+        assert_eq!(secs("30s"), Ok(30));
+        assert_eq!(secs("1h30m"), Ok(5400));
+        assert_eq!(secs("2d"), Ok(172_800));
+        assert_eq!(secs("1 week 3 days"), Ok(864_000));
+        assert_eq!(secs("1H, 5 Minutes"), Ok(3900));
+        assert_eq!(secs("  10 secs  "), Ok(10));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(secs(""), Err("Expected a duration".to_string()));
+        assert_eq!(secs("h"), Err("Expected a number at `h`".to_string()));
+        assert_eq!(secs("10"), Err("Missing unit after `10`".to_string()));
+        assert_eq!(secs("5 parsecs"), Err("Unknown unit `parsecs`".to_string()));
+        assert_eq!(secs("-5s"), Err("Expected a number at `-5s`".to_string()));
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        let too_long = Err("Duration is too long".to_string());
+
+        assert_eq!(secs("18446744073709551615s"), Ok(u64::MAX));
+        assert_eq!(secs("18446744073709551616s"), too_long);
+        assert_eq!(secs("18446744073709551615w"), too_long);
+        assert_eq!(secs("18446744073709551615s 1s"), too_long);
+    }
+
+    #[test]
+    fn rejects_non_ascii_input() {
+        assert_eq!(secs("１h"), Err("Expected a number at `１h`".to_string()));
+        assert_eq!(secs("5秒"), Err("Unknown unit `秒`".to_string()));
+        assert_eq!(secs("5é"), Err("Unknown unit `é`".to_string()));
+        assert_eq!(secs("5m ⏰"), Err("Expected a number at `⏰`".to_string()));
+    }
+
+    #[test]
+    fn checks_bounds() {
+        type Bounded = Duration<60, 3600>;
+
+        assert_eq!(Bounded::parse_str("59s"), Err("Duration must be at least 1m".to_string()));
+        assert_eq!(Bounded::parse_str("1m").map(|d| d.as_secs()), Ok(60));
+        assert_eq!(Bounded::parse_str("1h").map(|d| d.as_secs()), Ok(3600));
+        assert_eq!(Bounded::parse_str("1h 1s"), Err("Duration must be at most 1h".to_string()));
+        assert_eq!(Duration::<0>::parse_str("0s").map(|d| d.as_secs()), Ok(0));
+        assert!(Duration::<0>::parse_str("18446744073709551615s").is_ok());
+    }
+
+    #[test]
+    fn formats_largest_units() {
+        assert_eq!(format_secs(0), "0s");
+        assert_eq!(format_secs(90_061), "1d 1h 1m 1s");
+        assert_eq!(format_secs(1_209_600), "2w");
+        assert_eq!("2h".parse::<Duration>().map(|d| d.to_string()), Ok("2h".to_string()));
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use async_trait::async_trait;
+use twilight_http::request::channel::reaction::RequestReactionType;
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use twilight_model::id::Id;
+use twilight_model::id::marker::EmojiMarker;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::snowflake::parse_id;
+use super::InvalidInput;
+
+/// An emoji, provided either as an unicode emoji or as a custom emoji like `<:name:id>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Emoji {
+    /// An unicode emoji.
+    Unicode(String),
+    /// A custom guild emoji.
+    Custom {
+        /// Whether the emoji is animated.
+        animated: bool,
+        /// The name of the emoji.
+        name: String,
+        /// The id of the emoji.
+        id: Id<EmojiMarker>,
+    },
+}
+
+impl Emoji {
+    /// Gets the emoji as a reaction, which can be used to react to messages.
+    pub fn as_reaction(&self) -> RequestReactionType<'_> {
+        match self {
+            Self::Unicode(name) => RequestReactionType::Unicode { name: name.as_str() },
+            Self::Custom { name, id, .. } => RequestReactionType::Custom {
+                id: *id,
+                name: Some(name.as_str())
+            }
+        }
+    }
+
+    fn parse_custom(input: &str) -> Option<Self> {
+        let inner = input.strip_prefix('<')?.strip_suffix('>')?;
+        let mut parts = inner.split(':');
+
+        let animated = match parts.next()? {
+            "" => false,
+            "a" => true,
+            _ => return None
+        };
+        let name = parts.next()?;
+        let id = parse_id(parts.next()?)?;
+
+        if name.is_empty() || parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self::Custom {
+            animated,
+            name: name.to_string(),
+            id
+        })
+    }
+
+    fn parse_unicode(input: &str) -> Option<Self> {
+        let mut chars = input.chars().peekable();
+        let mut valid = true;
+
+        // Digits, `#` and `*` are only emojis as the base of a keycap.
+        while let Some(c) = chars.next() {
+            let keycap_base = matches!(c, '0'..='9' | '#' | '*')
+                && chars.peek().copied().is_some_and(is_emoji_modifier);
+
+            valid &= is_emoji_char(c) || is_emoji_modifier(c) || keycap_base;
+        }
+
+        let is_emoji = valid && input.chars().any(|c| is_emoji_char(c) || c == '\u{20E3}');
+
+        if is_emoji {
+            Some(Self::Unicode(input.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Emoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unicode(emoji) => f.write_str(emoji),
+            Self::Custom { animated, name, id } => {
+                write!(f, "<{}:{}:{}>", if *animated { "a" } else { "" }, name, id)
+            }
+        }
+    }
+}
+
+impl FromStr for Emoji {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+
+        Self::parse_custom(input)
+            .or_else(|| Self::parse_unicode(input))
+            .ok_or_else(|| InvalidInput(String::from("Emoji expected")))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Emoji {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+
+        input.parse()
+            .map_err(|why: InvalidInput| error::<_, Self>(ctx, "Emoji", "vesper.parse.emoji", &[], &why.0))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.min_length = Some(1);
+        option.max_length = Some(64);
+    }
+}
+
+/// Whether the character belongs to one of the unicode blocks containing emojis.
+fn is_emoji_char(c: char) -> bool {
+    matches!(c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139 | 0x3030 | 0x303D | 0x3297 | 0x3299
+        | 0x2194..=0x21AA
+        | 0x2300..=0x23FF
+        | 0x24C2
+        | 0x25AA..=0x25FE
+        | 0x2600..=0x27BF
+        | 0x2934..=0x2935
+        | 0x2B00..=0x2BFF
+        | 0x1F000..=0x1FAFF
+    )
+}
+
+/// Whether the character modifies a previous emoji, such as variation selectors, the zero width
+/// joiner, the keycap or tags.
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0x200D | 0x20E3 | 0xFE0E | 0xFE0F | 0xE0020..=0xE007F)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unicode(input: &str) -> Result<Emoji, InvalidInput> {
+        Ok(Emoji::Unicode(input.to_string()))
+    }
+
+    fn custom(animated: bool, name: &str, id: u64) -> Result<Emoji, InvalidInput> {
+        Ok(Emoji::Custom { animated, name: name.to_string(), id: Id::new(id) })
+    }
+
+    #[test]
+    fn parses_unicode_emojis() {
+        assert_eq!("👍".parse(), unicode("👍"));
+        assert_eq!(" ❤️ ".parse(), unicode("❤️"));
+        assert_eq!("👍🏽".parse(), unicode("👍🏽"));
+        assert_eq!("👨‍👩‍👧".parse(), unicode("👨‍👩‍👧"));
+        assert_eq!("🏳️‍🌈".parse(), unicode("🏳️‍🌈"));
+        assert_eq!("🏴󠁧󠁢󠁳󠁣󠁴󠁿".parse(), unicode("🏴󠁧󠁢󠁳󠁣󠁴󠁿"));
+        assert_eq!("1️⃣".parse(), unicode("1️⃣"));
+        assert_eq!("#️⃣".parse(), unicode("#️⃣"));
+    }
+
+    #[test]
+    fn parses_custom_emojis() {
+        assert_eq!("<:vesper:123>".parse(), custom(false, "vesper", 123));
+        assert_eq!("<a:vesper:123>".parse(), custom(true, "vesper", 123));
+        assert_eq!("<:vesper:123>".parse::<Emoji>().unwrap().to_string(), "<:vesper:123>");
+        assert_eq!("<a:vesper:123>".parse::<Emoji>().unwrap().to_string(), "<a:vesper:123>");
+    }
+
+    #[test]
+    fn rejects_invalid_emojis() {
+        let expected = Err(InvalidInput(String::from("Emoji expected")));
+
+        for input in [
+            "", "a", "1", "#", "*", "1⃣1", "👍a", "<:vesper:0>", "<::123>", "<b:vesper:123>",
+            "<:vesper:123:4>", "<:vesper:abc>", "<:vesper:123", ":vesper:123>", "<vesper:123>",
+            "<:vesper:18446744073709551616>", "\u{200D}", "\u{FE0F}",
+        ] {
+            assert_eq!(input.parse::<Emoji>(), expected, "`{}` parsed as an emoji", input);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+use async_trait::async_trait;
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use twilight_model::id::Id;
+use twilight_model::id::marker::{ChannelMarker, GuildMarker, MessageMarker};
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::snowflake::parse_id;
+use super::InvalidInput;
+
+const HOSTS: [&str; 6] = [
+    "discord.com",
+    "ptb.discord.com",
+    "canary.discord.com",
+    "discordapp.com",
+    "ptb.discordapp.com",
+    "canary.discordapp.com",
+];
+
+/// A reference to a message, provided either as a jump url like
+/// `https://discord.com/channels/guild_id/channel_id/message_id` or as a bare message id.
+///
+/// When a bare id is provided, the message is assumed to be in the channel the command was
+/// executed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageLink {
+    /// The guild the message was sent in, `None` if it was sent in a private channel.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// The channel the message was sent in.
+    pub channel_id: Id<ChannelMarker>,
+    /// The id of the message.
+    pub message_id: Id<MessageMarker>,
+}
+
+impl MessageLink {
+    /// Parses a jump url, returning `None` if it is not valid.
+    fn from_url(url: &str) -> Option<Self> {
+        let url = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+        let mut parts = url.split('/');
+
+        if !HOSTS.contains(&parts.next()?) || parts.next()? != "channels" {
+            return None;
+        }
+
+        let guild_id = match parts.next()? {
+            "@me" => None,
+            id => Some(parse_id(id)?)
+        };
+        let channel_id = parse_id(parts.next()?)?;
+        let message_id = parse_id(parts.next()?)?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            guild_id,
+            channel_id,
+            message_id
+        })
+    }
+}
+
+/// Parses a jump url, as bare ids can't be resolved without knowing the channel they belong to.
+impl FromStr for MessageLink {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::from_url(input.trim()).ok_or_else(|| InvalidInput(String::from("Invalid message link")))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for MessageLink {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+        let input = input.trim();
+
+        if let Some(message_id) = parse_id(input) {
+            return ctx.channel_id
+                .map(|channel_id| Self {
+                    guild_id: ctx.guild_id,
+                    channel_id,
+                    message_id
+                })
+                .ok_or_else(|| error::<_, Self>(
+                    ctx,
+                    "MessageLink",
+                    "vesper.parse.message_link_channel",
+                    &[],
+                    "Unknown channel, provide a message link"
+                ));
+        }
+
+        input.parse()
+            .map_err(|why: InvalidInput| error::<_, Self>(ctx, "MessageLink", "vesper.parse.message_link", &[], &why.0))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.min_length = Some(1);
+        option.max_length = Some(128);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(input: &str) -> Option<(Option<u64>, u64, u64)> {
+        input.parse::<MessageLink>().ok().map(|link| {
+            (link.guild_id.map(Id::get), link.channel_id.get(), link.message_id.get())
+        })
+    }
+
+    #[test]
+    fn parses_jump_urls() {
+        assert_eq!(link("https://discord.com/channels/1/2/3"), Some((Some(1), 2, 3)));
+        assert_eq!(link(" http://discordapp.com/channels/1/2/3 "), Some((Some(1), 2, 3)));
+        assert_eq!(link("https://ptb.discord.com/channels/1/2/3"), Some((Some(1), 2, 3)));
+        assert_eq!(link("https://canary.discordapp.com/channels/1/2/3"), Some((Some(1), 2, 3)));
+        assert_eq!(link("https://discord.com/channels/@me/2/3"), Some((None, 2, 3)));
+    }
+
+    #[test]
+    fn rejects_invalid_links() {
+        for input in [
+            "",
+            "3",
+            "discord.com/channels/1/2/3",
+            "ftp://discord.com/channels/1/2/3",
+            "https://evil.com/channels/1/2/3",
+            "https://discord.com.evil.com/channels/1/2/3",
+            "https://DISCORD.com/channels/1/2/3",
+            "https://discord.com/channels/1/2",
+            "https://discord.com/channels/1/2/3/",
+            "https://discord.com/channels/1/2/3/4",
+            "https://discord.com/channels/1/2/3?query",
+            "https://discord.com/guilds/1/2/3",
+            "https://discord.com/channels/0/2/3",
+            "https://discord.com/channels/1/0/3",
+            "https://discord.com/channels/@you/2/3",
+            "https://discord.com/channels/1/2/18446744073709551616",
+            "https://discord.com/channels/１/2/3",
+        ] {
+            assert_eq!(link(input), None, "`{}` parsed as a message link", input);
+        }
+    }
+}
@@ -1,14 +1,50 @@
 use std::ops::{Deref, DerefMut};
 use async_trait::async_trait;
+use thiserror::Error;
 use twilight_model::application::command::{CommandOption, CommandOptionType};
-use twilight_model::application::interaction::application_command::{CommandInteractionDataResolved, CommandOptionValue, InteractionChannel};
+use twilight_model::application::interaction::application_command::{CommandOptionValue, InteractionChannel, InteractionMember};
 use twilight_model::channel::ChannelType;
+use twilight_model::guild::Role;
 use twilight_model::id::Id;
-use twilight_model::id::marker::ChannelMarker;
-use crate::builder::WrappedClient;
-use crate::parse::{Parse, ParseError};
+use twilight_model::id::marker::{ChannelMarker, GenericMarker, UserMarker};
+use twilight_model::user::User;
+use crate::parse::{Parse, ParseContext, ParseError};
 use crate::parse_impl::error;
 
+mod color;
+mod duration;
+mod emoji;
+mod message_link;
+#[cfg(feature = "regex")]
+mod pattern;
+mod snowflake;
+mod url;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+/// Date parsers backed by the `chrono` crate.
+#[cfg(feature = "chrono")]
+pub mod chrono;
+/// Date parsers backed by the `time` crate.
+#[cfg(feature = "time")]
+pub mod time;
+
+pub use color::Color;
+pub use duration::Duration;
+pub use emoji::Emoji;
+pub use message_link::MessageLink;
+#[cfg(feature = "regex")]
+pub use pattern::{Pattern, RegexSource};
+pub use snowflake::Snowflake;
+pub use url::{Url, UrlPolicy, Web};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use datetime::{OffsetSource, Utc};
+
+/// The error returned when parsing a string into one of the parsers using `FromStr` fails,
+/// containing the reason why the input is not valid.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{0}")]
+pub struct InvalidInput(pub String);
+
 macro_rules! newtype_struct {
     ($($(#[$meta:meta])* $v: vis struct $name: ident($inner: ty)),* $(,)?) => {
         $(
@@ -44,13 +80,11 @@ macro_rules! parse_id {
     (@inner $name: ty, $kind: expr, [$($allowed: expr),* $(,)?]) => {
         #[async_trait]
         impl<T: Send + Sync> Parse<T> for $name {
-            async fn parse(
-                http_client: &WrappedClient,
-                data: &T,
-                value: Option<&CommandOptionValue>,
-                resolved: Option<&mut CommandInteractionDataResolved>
+            async fn parse_with_context(
+                ctx: &mut ParseContext<'_, T>,
+                value: Option<&CommandOptionValue>
             ) -> Result<Self, ParseError> {
-                Ok(Self(Id::parse(http_client, data, value, resolved).await?))
+                Ok(Self(Id::parse_with_context(ctx, value).await?))
             }
 
             fn kind() -> CommandOptionType {
@@ -73,17 +107,21 @@ macro_rules! parse_derived_channel {
     (@inner $name_t: ty, $id: ty, $name: literal) => {
         #[async_trait]
         impl<T: Send + Sync> Parse<T> for $name_t {
-            async fn parse(
-                http_client: &WrappedClient,
-                data: &T,
-                value: Option<&CommandOptionValue>,
-                resolved: Option<&mut CommandInteractionDataResolved>
+            async fn parse_with_context(
+                ctx: &mut ParseContext<'_, T>,
+                value: Option<&CommandOptionValue>
             ) -> Result<Self, ParseError> {
-                let id = <$id>::parse(http_client, data, value, None).await?;
+                let id = <$id>::parse_with_context(ctx, value).await?;
 
-                resolved.map(|items| items.channels.remove(&*id))
+                ctx.resolved.as_mut().map(|items| items.channels.remove(&*id))
                     .flatten()
-                    .ok_or_else(|| error($name, true, concat!($name, " expected")))
+                    .ok_or_else(|| error::<_, Self>(
+                        ctx,
+                        $name,
+                        "vesper.parse.channel",
+                        &[],
+                        concat!($name, " expected")
+                    ))
                     .map(Self)
             }
 
@@ -136,3 +174,68 @@ parse_derived_channel! {
     PrivateThread, PrivateThreadId, "Private Thread",
     Thread, ThreadId, "Thread"
 }
+
+/// A guild member along with its user, parsed from an user option.
+///
+/// Parsing fails if the command was not executed in a guild or the user is not a member of it.
+#[derive(Debug, Clone)]
+pub struct Member {
+    /// The user of the member.
+    pub user: User,
+    /// The guild specific data of the member.
+    pub member: InteractionMember,
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Member {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let id = <Id<UserMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
+
+        ctx.resolved.as_mut()
+            .and_then(|items| Some(Self {
+                user: items.users.remove(&id)?,
+                member: items.members.remove(&id)?
+            }))
+            .ok_or_else(|| error::<_, Self>(ctx, "Member", "vesper.parse.member", &[], "Member expected"))
+    }
+
+    fn kind() -> CommandOptionType {
+        <Id<UserMarker> as Parse<T>>::kind()
+    }
+}
+
+/// An object that parses into either an user or a role, resolved from a mentionable option.
+#[derive(Debug, Clone)]
+pub enum Mentionable {
+    /// An user, along with its member data if the command was executed in a guild.
+    User(User, Option<InteractionMember>),
+    /// A role.
+    Role(Role),
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Mentionable {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let id = <Id<GenericMarker> as Parse<T>>::parse_with_context(ctx, value).await?;
+
+        ctx.resolved.as_mut()
+            .and_then(|items| {
+                if let Some(user) = items.users.remove(&id.cast()) {
+                    Some(Self::User(user, items.members.remove(&id.cast())))
+                } else {
+                    items.roles.remove(&id.cast()).map(Self::Role)
+                }
+            })
+            .ok_or_else(|| error::<_, Self>(ctx, "Mentionable", "vesper.parse.mentionable", &[], "User or role expected"))
+    }
+
+    fn kind() -> CommandOptionType {
+        <Id<GenericMarker> as Parse<T>>::kind()
+    }
+}
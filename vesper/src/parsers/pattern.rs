@@ -0,0 +1,129 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::str::FromStr;
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use regex::Regex;
+use twilight_model::application::command::CommandOptionType;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::InvalidInput;
+
+/// Provides the regular expression a [pattern](Pattern) is validated against.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::parsers::{Pattern, RegexSource};
+///
+/// pub struct HexCode;
+///
+/// impl RegexSource for HexCode {
+///     const REGEX: &'static str = "^[0-9a-fA-F]{6}$";
+///     const DESCRIPTION: &'static str = "Expected a six digit hex code";
+/// }
+///
+/// type HexCodeInput = Pattern<HexCode>;
+/// ```
+pub trait RegexSource: 'static {
+    /// The regular expression the input must match, anchors must be included to match the whole
+    /// input.
+    const REGEX: &'static str;
+    /// The error message shown when the input doesn't match the expression, it is also used as a
+    /// key to [translate](crate::parse::ParseContext::translate) the message.
+    const DESCRIPTION: &'static str = "Input does not match the expected format";
+}
+
+/// A string validated against the regular expression provided by the given
+/// [source](RegexSource).
+///
+/// The expression is compiled once and cached for later uses, an invalid expression makes
+/// parsing fail with an error instead.
+pub struct Pattern<R: RegexSource>(String, PhantomData<fn() -> R>);
+
+impl<R: RegexSource> Pattern<R> {
+    /// Consumes the wrapper, returning the inner string.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Gets the compiled expression of the source, returning an error if it is not valid.
+    pub fn regex() -> Result<Regex, regex::Error> {
+        static CACHE: RwLock<Option<HashMap<TypeId, Result<Regex, regex::Error>>>> =
+            RwLock::new(None);
+
+        if let Some(regex) = CACHE.read().as_ref().and_then(|cache| cache.get(&TypeId::of::<R>())) {
+            return regex.clone();
+        }
+
+        CACHE.write()
+            .get_or_insert_with(HashMap::new)
+            .entry(TypeId::of::<R>())
+            .or_insert_with(|| Regex::new(R::REGEX))
+            .clone()
+    }
+
+    fn matches(input: &str) -> Result<bool, String> {
+        Self::regex()
+            .map(|regex| regex.is_match(input))
+            .map_err(|why| format!("Invalid pattern `{}`: {}", R::REGEX, why))
+    }
+}
+
+impl<R: RegexSource> Deref for Pattern<R> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<R: RegexSource> Clone for Pattern<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<R: RegexSource> Debug for Pattern<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Pattern({:?})", self.0)
+    }
+}
+
+impl<R: RegexSource> FromStr for Pattern<R> {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if Self::matches(input).map_err(InvalidInput)? {
+            Ok(Self(input.to_string(), PhantomData))
+        } else {
+            Err(InvalidInput(R::DESCRIPTION.to_string()))
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, R: RegexSource> Parse<T> for Pattern<R> {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let value = String::parse_with_context(ctx, value).await?;
+
+        let matches = Self::matches(&value)
+            .map_err(|why| error::<_, Self>(ctx, "Pattern", "vesper.parse.invalid_pattern", &[], &why))?;
+        if matches {
+            Ok(Self(value, PhantomData))
+        } else {
+            Err(error::<_, Self>(ctx, "Pattern", R::DESCRIPTION, &[], R::DESCRIPTION))
+        }
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
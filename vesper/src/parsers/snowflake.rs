@@ -0,0 +1,127 @@
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use async_trait::async_trait;
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use twilight_model::id::Id;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::InvalidInput;
+
+/// An id provided as a string, useful to reference items which can't be selected using the
+/// discord options, such as users outside the guild or messages.
+///
+/// Mentions like `<@id>`, `<#id>` or `<@&id>` are also accepted.
+pub struct Snowflake<M>(pub Id<M>);
+
+impl<M> Clone for Snowflake<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Snowflake<M> {}
+
+impl<M> Deref for Snowflake<M> {
+    type Target = Id<M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<M> DerefMut for Snowflake<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<M> Debug for Snowflake<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Snowflake({})", self.0)
+    }
+}
+
+impl<M> Display for Snowflake<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<M> FromStr for Snowflake<M> {
+    type Err = InvalidInput;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let id = input.strip_prefix('<')
+            .and_then(|mention| mention.strip_suffix('>'))
+            .map(|mention| mention.trim_start_matches(['@', '#', '!', '&']))
+            .unwrap_or(input);
+
+        parse_id(id)
+            .map(Self)
+            .ok_or_else(|| InvalidInput(String::from("Invalid id")))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, M> Parse<T> for Snowflake<M> {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+
+        input.parse().map_err(|why: InvalidInput| error::<_, Self>(ctx, "Snowflake", "vesper.parse.snowflake", &[], &why.0))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.min_length = Some(1);
+        option.max_length = Some(24);
+    }
+}
+
+/// Parses an id made only of digits.
+pub(crate) fn parse_id<M>(input: &str) -> Option<Id<M>> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    input.parse().ok().and_then(Id::new_checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::marker::GenericMarker;
+    use super::*;
+
+    fn id(input: &str) -> Option<u64> {
+        input.parse::<Snowflake<GenericMarker>>().ok().map(|id| id.get())
+    }
+
+    #[test]
+    fn parses_ids_and_mentions() {
+        assert_eq!(id("123"), Some(123));
+        assert_eq!(id(" 123 "), Some(123));
+        assert_eq!(id("<@123>"), Some(123));
+        assert_eq!(id("<@!123>"), Some(123));
+        assert_eq!(id("<#123>"), Some(123));
+        assert_eq!(id("<@&123>"), Some(123));
+        assert_eq!(id("18446744073709551615"), Some(u64::MAX));
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        for input in [
+            "", "0", "<@0>", "-1", "+1", "1.5", "1e3", "abc", "<@123", "@123>", "<@>", "<:name:123>",
+            "18446744073709551616", "１２３", "<@١٢٣>",
+        ] {
+            assert_eq!(id(input), None, "`{}` parsed as an id", input);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use async_trait::async_trait;
+use ::time::{Date, Month, UtcOffset};
+use twilight_model::application::command::CommandOptionType;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use crate::parse::{Parse, ParseContext, ParseError};
+use crate::parse_impl::error;
+use super::datetime::{parse_datetime, OffsetSource, RawDateTime, Utc};
+
+/// A [time date](::time::OffsetDateTime) provided as a string, such as `2024-05-01 18:30`,
+/// `2024-05-01T18:30:00+02:00` or `in 2h`.
+///
+/// Dates without an explicit offset are interpreted using the offset given by the
+/// [offset source](OffsetSource), which defaults to [UTC](Utc).
+pub struct OffsetDateTime<S = Utc>(pub ::time::OffsetDateTime, PhantomData<fn() -> S>);
+
+impl<S> OffsetDateTime<S> {
+    /// Consumes the wrapper, returning the inner date.
+    pub fn into_inner(self) -> ::time::OffsetDateTime {
+        self.0
+    }
+}
+
+impl<S> Deref for OffsetDateTime<S> {
+    type Target = ::time::OffsetDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S> DerefMut for OffsetDateTime<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T, S> Parse<T> for OffsetDateTime<S>
+where
+    T: Send + Sync,
+    S: OffsetSource<T>
+{
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let input = String::parse_with_context(ctx, value).await?;
+        let raw = parse_datetime(&input)
+            .map_err(|why| error::<_, Self>(ctx, "OffsetDateTime", "vesper.parse.datetime", &[], &why))?;
+
+        let default_offset = match raw {
+            RawDateTime::Absolute { offset: None, .. } => S::offset(ctx).await,
+            _ => 0
+        };
+
+        resolve(raw, default_offset)
+            .map(|date| Self(date, PhantomData))
+            .ok_or_else(|| error::<_, Self>(ctx, "OffsetDateTime", "vesper.parse.datetime", &[], "Invalid date"))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// Converts the given date into a time one, using the given offset when the date doesn't
+/// specify one, returning `None` if the date is not valid.
+fn resolve(raw: RawDateTime, default_offset: i32) -> Option<::time::OffsetDateTime> {
+    match raw {
+        RawDateTime::Relative(duration) => ::time::Duration::try_from(duration)
+            .ok()
+            .and_then(|duration| ::time::OffsetDateTime::now_utc().checked_add(duration)),
+        RawDateTime::Absolute { year, month, day, hour, minute, second, offset } => {
+            UtcOffset::from_whole_seconds(offset.unwrap_or(default_offset)).ok().and_then(|offset| {
+                let month = Month::try_from(month).ok()?;
+                let date = Date::from_calendar_date(year, month, day).ok()?
+                    .with_hms(hour, minute, second).ok()?;
+
+                Some(date.assume_offset(offset))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+    use super::*;
+
+    fn absolute(year: i32, month: u8, day: u8, offset: Option<i32>) -> RawDateTime {
+        RawDateTime::Absolute { year, month, day, hour: 18, minute: 30, second: 15, offset }
+    }
+
+    /// Resolves the given date, returning its unix timestamp and offset.
+    fn timestamp(raw: RawDateTime, default_offset: i32) -> Option<(i64, i32)> {
+        resolve(raw, default_offset).map(|date| (date.unix_timestamp(), date.offset().whole_seconds()))
+    }
+
+    #[test]
+    fn resolves_offsets() {
+        assert_eq!(timestamp(absolute(2024, 5, 1, Some(7200)), 0), Some((1_714_581_015, 7200)));
+        assert_eq!(timestamp(absolute(2024, 5, 1, None), -19_800), Some((1_714_608_015, -19_800)));
+        assert_eq!(timestamp(absolute(2024, 5, 1, Some(0)), 3600), Some((1_714_588_215, 0)));
+        assert_eq!(timestamp(absolute(2024, 5, 1, None), 100_000), None);
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert_eq!(timestamp(absolute(2023, 2, 29, None), 0), None);
+        assert_eq!(timestamp(absolute(2024, 13, 1, None), 0), None);
+
+        let invalid_hour = RawDateTime::Absolute {
+            year: 2024, month: 5, day: 1, hour: 24, minute: 0, second: 0, offset: None
+        };
+        assert_eq!(resolve(invalid_hour, 0), None);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        assert!(timestamp(absolute(Date::MAX.year(), 12, 31, Some(0)), 0).is_some());
+        assert!(timestamp(absolute(Date::MAX.year() + 1, 1, 1, Some(0)), 0).is_none());
+        assert!(timestamp(absolute(Date::MIN.year(), 1, 1, Some(0)), 0).is_some());
+        assert!(timestamp(absolute(Date::MIN.year() - 1, 12, 31, Some(0)), 0).is_none());
+        assert!(timestamp(absolute(i32::MAX, 1, 1, Some(0)), 0).is_none());
+    }
+
+    #[test]
+    fn resolves_relative_dates() {
+        let now = ::time::OffsetDateTime::now_utc();
+        let date = resolve(RawDateTime::Relative(StdDuration::from_secs(3600)), 0).unwrap();
+
+        assert!(date - now >= ::time::Duration::hours(1));
+        assert_eq!(resolve(RawDateTime::Relative(StdDuration::from_secs(u64::MAX)), 0), None);
+    }
+}
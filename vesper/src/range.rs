@@ -27,9 +27,26 @@ mod sealed {
     }
 
     number![i8, i16, i32, i64, isize, u8, u16, u32, u64, usize];
+
+    /// A trait used to specify the values [float range](super::FloatRange) can take.
+    pub trait Float: Copy + Debug + Display {
+        fn as_f64(&self) -> f64;
+    }
+
+    impl Float for f32 {
+        fn as_f64(&self) -> f64 {
+            *self as f64
+        }
+    }
+
+    impl Float for f64 {
+        fn as_f64(&self) -> f64 {
+            *self
+        }
+    }
 }
 
-use sealed::Number;
+use sealed::{Float, Number};
 
 /// A range-like type used to constraint the input provided by the user. This is equivalent to
 /// using a [RangeInclusive], but implements the [parse] trait.
@@ -58,20 +75,20 @@ impl<T, E, const START: i64, const END: i64> Parse<T> for Range<E, START, END>
         T: Send + Sync,
         E: Parse<T> + Number
 {
-    async fn parse(
-        http_client: &WrappedClient,
-        data: &T,
-        value: Option<&CommandOptionValue>,
-        resolved: Option<&mut CommandInteractionDataResolved>
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
     ) -> Result<Self, ParseError> {
-        let value = E::parse(http_client, data, value, resolved).await?;
+        let value = E::parse_with_context(ctx, value).await?;
 
         let v = value.as_i64();
 
         if v < START || v > END {
-            return Err(error(
+            return Err(error::<_, Self>(
+                ctx,
                 &format!("Range<{}, {}, {}>", type_name::<E>(), START, END),
-                true,
+                "vesper.parse.out_of_range",
+                &[],
                 "Input out of range"
             ));
         }
@@ -95,3 +112,141 @@ impl<T: Number, const START: i64, const END: i64> Debug for Range<T, START, END>
         write!(f, "Range<{}, {}, {}>({})", type_name::<T>(), START, END, self.0)
     }
 }
+
+/// A range-like type used to constraint decimal numbers provided by the user. As floats can't be
+/// used as const generics, the bounds are given as integers which are divided by
+/// `10^DECIMALS`, so `FloatRange<f64, -15, 25, 1>` accepts values from `-1.5` to `2.5`.
+#[derive(Copy, Clone)]
+pub struct FloatRange<T: Float, const START: i64, const END: i64, const DECIMALS: u32 = 0>(T);
+
+impl<T: Float, const START: i64, const END: i64, const DECIMALS: u32> FloatRange<T, START, END, DECIMALS> {
+    /// Gets the lower bound of the range.
+    pub fn start() -> f64 {
+        START as f64 / 10f64.powi(DECIMALS as i32)
+    }
+
+    /// Gets the upper bound of the range.
+    pub fn end() -> f64 {
+        END as f64 / 10f64.powi(DECIMALS as i32)
+    }
+}
+
+impl<T: Float, const START: i64, const END: i64, const DECIMALS: u32> Deref for FloatRange<T, START, END, DECIMALS> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Float, const START: i64, const END: i64, const DECIMALS: u32> DerefMut for FloatRange<T, START, END, DECIMALS> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T, E, const START: i64, const END: i64, const DECIMALS: u32> Parse<T> for FloatRange<E, START, END, DECIMALS>
+    where
+        T: Send + Sync,
+        E: Parse<T> + Float
+{
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let value = E::parse_with_context(ctx, value).await?;
+
+        let v = value.as_f64();
+
+        if v < Self::start() || v > Self::end() {
+            return Err(error::<_, Self>(
+                ctx,
+                &format!("FloatRange<{}, {}, {}>", type_name::<E>(), Self::start(), Self::end()),
+                "vesper.parse.out_of_range",
+                &[],
+                "Input out of range"
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    fn kind() -> CommandOptionType {
+        E::kind()
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        use twilight_model::application::command::CommandOptionValue;
+        option.max_value = Some(CommandOptionValue::Number(Self::end()));
+        option.min_value = Some(CommandOptionValue::Number(Self::start()));
+    }
+}
+
+impl<T: Float, const START: i64, const END: i64, const DECIMALS: u32> Debug for FloatRange<T, START, END, DECIMALS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "FloatRange<{}, {}, {}>({})", type_name::<T>(), Self::start(), Self::end(), self.0)
+    }
+}
+
+/// A string whose length, in characters, must be between `MIN` and `MAX`, both inclusive.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Length<const MIN: u16, const MAX: u16>(String);
+
+impl<const MIN: u16, const MAX: u16> Length<MIN, MAX> {
+    /// Consumes the wrapper, returning the inner string.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const MIN: u16, const MAX: u16> Deref for Length<MIN, MAX> {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: u16, const MAX: u16> DerefMut for Length<MIN, MAX> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, const MIN: u16, const MAX: u16> Parse<T> for Length<MIN, MAX> {
+    async fn parse_with_context(
+        ctx: &mut ParseContext<'_, T>,
+        value: Option<&CommandOptionValue>
+    ) -> Result<Self, ParseError> {
+        let value = String::parse_with_context(ctx, value).await?;
+
+        let length = value.chars().count();
+
+        if length < MIN as usize || length > MAX as usize {
+            return Err(error::<_, Self>(
+                ctx,
+                &format!("Length<{}, {}>", MIN, MAX),
+                "vesper.parse.length",
+                &[("min", &MIN), ("max", &MAX)],
+                &format!("Input must be between {} and {} characters long", MIN, MAX)
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.min_length = Some(MIN);
+        option.max_length = Some(MAX);
+    }
+}
+
+impl<const MIN: u16, const MAX: u16> Debug for Length<MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Length<{}, {}>({:?})", MIN, MAX, self.0)
+    }
+}
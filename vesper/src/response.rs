@@ -0,0 +1,386 @@
+use std::future::IntoFuture;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex as AsyncMutex;
+use twilight_model::channel::message::{AllowedMentions, Embed, MessageFlags};
+use twilight_model::http::attachment::Attachment;
+use crate::{
+    context::ResponseState,
+    error::ReplyError,
+    if_some,
+    twilight_exports::*,
+    BoxFuture
+};
+
+/// The interaction being responded, along with the state of its response.
+#[derive(Clone)]
+pub(crate) struct Responder<'a> {
+    pub(crate) http_client: &'a Client,
+    pub(crate) application_id: Id<ApplicationMarker>,
+    pub(crate) interaction_id: Id<InteractionMarker>,
+    pub(crate) token: String,
+    pub(crate) state: Arc<AsyncMutex<ResponseState>>,
+    pub(crate) expires_at: Instant,
+}
+
+impl<'a> Responder<'a> {
+    fn interaction_client(&self) -> InteractionClient<'a> {
+        self.http_client.interaction(self.application_id)
+    }
+
+    fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+/// The message a [reply handle](ReplyHandle) points to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReplyTarget {
+    /// The original response to the interaction.
+    Original,
+    /// A followup message.
+    Followup(Id<MessageMarker>),
+}
+
+#[derive(Copy, Clone)]
+enum ReplyKind {
+    Reply,
+    EditReply,
+    Followup,
+    UpdateMessage,
+    Edit(ReplyTarget),
+}
+
+/// A builder used to respond to an interaction, obtained using
+/// [`SlashContext::reply`], [`SlashContext::edit_reply`], [`SlashContext::followup`] or
+/// [`ComponentContext::update_message`].
+///
+/// The builder picks the endpoint to use depending on the [state](ResponseState) of the response,
+/// to send the message, `.await` the builder.
+///
+/// [`SlashContext::reply`]: crate::context::SlashContext::reply
+/// [`SlashContext::edit_reply`]: crate::context::SlashContext::edit_reply
+/// [`SlashContext::followup`]: crate::context::SlashContext::followup
+/// [`ComponentContext::update_message`]: crate::context::ComponentContext::update_message
+#[must_use = "Replies are not sent unless `.await`ed"]
+pub struct ReplyBuilder<'a> {
+    responder: Responder<'a>,
+    kind: ReplyKind,
+    content: Option<String>,
+    embeds: Option<Vec<Embed>>,
+    components: Option<Vec<Component>>,
+    attachments: Option<Vec<Attachment>>,
+    allowed_mentions: Option<AllowedMentions>,
+    ephemeral: bool,
+}
+
+impl<'a> ReplyBuilder<'a> {
+    fn new(responder: Responder<'a>, kind: ReplyKind) -> Self {
+        Self {
+            responder,
+            kind,
+            content: None,
+            embeds: None,
+            components: None,
+            attachments: None,
+            allowed_mentions: None,
+            ephemeral: false,
+        }
+    }
+
+    pub(crate) fn reply(responder: Responder<'a>) -> Self {
+        Self::new(responder, ReplyKind::Reply)
+    }
+
+    pub(crate) fn edit_reply(responder: Responder<'a>) -> Self {
+        Self::new(responder, ReplyKind::EditReply)
+    }
+
+    pub(crate) fn followup(responder: Responder<'a>) -> Self {
+        Self::new(responder, ReplyKind::Followup)
+    }
+
+    pub(crate) fn update_message(responder: Responder<'a>) -> Self {
+        Self::new(responder, ReplyKind::UpdateMessage)
+    }
+
+    /// Sets the content of the message.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Adds an embed to the message.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.get_or_insert_with(Vec::new).push(embed);
+        self
+    }
+
+    /// Sets the embeds of the message.
+    pub fn embeds(mut self, embeds: impl IntoIterator<Item = Embed>) -> Self {
+        self.embeds = Some(embeds.into_iter().collect());
+        self
+    }
+
+    /// Sets the components of the message.
+    pub fn components(mut self, components: impl IntoIterator<Item = Component>) -> Self {
+        self.components = Some(components.into_iter().collect());
+        self
+    }
+
+    /// Sets the attachments of the message.
+    pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments = Some(attachments.into_iter().collect());
+        self
+    }
+
+    /// Sets the mentions allowed in the message.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Sets whether the message is only visible to the user who invoked the interaction.
+    ///
+    /// This has no effect when editing messages, and the original response will keep the
+    /// visibility used to defer the interaction.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    fn flags(&self) -> Option<MessageFlags> {
+        if self.ephemeral && !matches!(self.kind, ReplyKind::UpdateMessage) {
+            Some(MessageFlags::EPHEMERAL)
+        } else {
+            None
+        }
+    }
+
+    async fn send(self) -> Result<ReplyHandle<'a>, ReplyError> {
+        if self.responder.is_expired() {
+            return Err(ReplyError::TokenExpired);
+        }
+        let responder = self.responder.clone();
+        let mut state = responder.state.lock().await;
+
+        let (target, message) = match (self.kind, *state) {
+            (ReplyKind::Edit(target), _) => (target, Some(self.update(target).await?)),
+            (_, ResponseState::Pending) => {
+                self.create_response().await?;
+                (ReplyTarget::Original, None)
+            },
+            (_, ResponseState::Deferred)
+            | (ReplyKind::EditReply | ReplyKind::UpdateMessage, ResponseState::Responded) => {
+                (ReplyTarget::Original, Some(self.update(ReplyTarget::Original).await?))
+            },
+            (_, ResponseState::Responded) => {
+                let message = self.create_followup().await?;
+                (ReplyTarget::Followup(message.id), Some(message))
+            }
+        };
+
+        if !matches!(self.kind, ReplyKind::Edit(_)) {
+            *state = ResponseState::Responded;
+        }
+
+        Ok(ReplyHandle {
+            responder: self.responder,
+            target,
+            message
+        })
+    }
+
+    async fn create_response(&self) -> Result<(), ReplyError> {
+        let kind = match self.kind {
+            ReplyKind::UpdateMessage => InteractionResponseType::UpdateMessage,
+            _ => InteractionResponseType::ChannelMessageWithSource
+        };
+
+        let client = self.responder.interaction_client();
+        client.create_response(
+            self.responder.interaction_id,
+            &self.responder.token,
+            &InteractionResponse {
+                kind,
+                data: Some(InteractionResponseData {
+                    allowed_mentions: self.allowed_mentions.clone(),
+                    attachments: self.attachments.clone(),
+                    components: self.components.clone(),
+                    content: self.content.clone(),
+                    embeds: self.embeds.clone(),
+                    flags: self.flags(),
+                    ..Default::default()
+                })
+            }
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn create_followup(&self) -> Result<Message, ReplyError> {
+        let client = self.responder.interaction_client();
+        let mut request = client.create_followup(&self.responder.token)
+            .allowed_mentions(self.allowed_mentions.as_ref());
+
+        if_some!(&self.content, |c| request = request.content(c)?);
+        if_some!(&self.embeds, |e| request = request.embeds(e)?);
+        if_some!(&self.components, |c| request = request.components(c)?);
+        if_some!(&self.attachments, |a| request = request.attachments(a)?);
+        if_some!(self.flags(), |f| request = request.flags(f));
+
+        Ok(request.await?.model().await?)
+    }
+
+    async fn update(&self, target: ReplyTarget) -> Result<Message, ReplyError> {
+        let client = self.responder.interaction_client();
+
+        let message = match target {
+            ReplyTarget::Original => {
+                let mut request = client.update_response(&self.responder.token)
+                    .allowed_mentions(self.allowed_mentions.as_ref());
+
+                if_some!(&self.content, |c| request = request.content(Some(c.as_str()))?);
+                if_some!(&self.embeds, |e| request = request.embeds(Some(e.as_slice()))?);
+                if_some!(&self.components, |c| request = request.components(Some(c.as_slice()))?);
+                if_some!(&self.attachments, |a| request = request.attachments(a)?);
+
+                request.await?.model().await?
+            },
+            ReplyTarget::Followup(id) => {
+                let mut request = client.update_followup(&self.responder.token, id)
+                    .allowed_mentions(self.allowed_mentions.as_ref());
+
+                if_some!(&self.content, |c| request = request.content(Some(c.as_str()))?);
+                if_some!(&self.embeds, |e| request = request.embeds(Some(e.as_slice()))?);
+                if_some!(&self.components, |c| request = request.components(Some(c.as_slice()))?);
+                if_some!(&self.attachments, |a| request = request.attachments(a)?);
+
+                request.await?.model().await?
+            }
+        };
+
+        Ok(message)
+    }
+}
+
+impl<'a> IntoFuture for ReplyBuilder<'a> {
+    type Output = Result<ReplyHandle<'a>, ReplyError>;
+    type IntoFuture = BoxFuture<'a, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+/// A handle to a message sent using a [reply builder](ReplyBuilder), which can be used to edit
+/// or delete the message later.
+pub struct ReplyHandle<'a> {
+    responder: Responder<'a>,
+    target: ReplyTarget,
+    message: Option<Message>,
+}
+
+impl<'a> ReplyHandle<'a> {
+    /// Gets the message this handle points to.
+    pub fn target(&self) -> ReplyTarget {
+        self.target
+    }
+
+    /// Gets the message returned by discord, this is `None` when the message was the initial
+    /// response to the interaction, as discord does not return it.
+    pub fn message(&self) -> Option<&Message> {
+        self.message.as_ref()
+    }
+
+    /// Fetches the message this handle points to.
+    pub async fn fetch(&self) -> Result<Message, ReplyError> {
+        let client = self.responder.interaction_client();
+        let message = match self.target {
+            ReplyTarget::Original => client.response(&self.responder.token).await?.model().await?,
+            ReplyTarget::Followup(id) => client.followup(&self.responder.token, id).await?.model().await?
+        };
+
+        Ok(message)
+    }
+
+    /// Returns a [builder](ReplyBuilder) used to edit the message this handle points to.
+    pub fn edit(&self) -> ReplyBuilder<'a> {
+        ReplyBuilder::new(self.responder.clone(), ReplyKind::Edit(self.target))
+    }
+
+    /// Deletes the message this handle points to.
+    pub async fn delete(self) -> Result<(), ReplyError> {
+        delete(&self.responder, self.target).await
+    }
+}
+
+pub(crate) async fn defer(responder: &Responder<'_>, ephemeral: bool) -> Result<(), twilight_http::Error> {
+    let mut state = responder.state.lock().await;
+    if *state != ResponseState::Pending {
+        return Ok(());
+    }
+
+    responder.interaction_client()
+        .create_response(
+            responder.interaction_id,
+            &responder.token,
+            &InteractionResponse {
+                kind: InteractionResponseType::DeferredChannelMessageWithSource,
+                data: if ephemeral {
+                    Some(InteractionResponseData {
+                        flags: Some(MessageFlags::EPHEMERAL),
+                        ..Default::default()
+                    })
+                } else {
+                    None
+                },
+            },
+        )
+        .await?;
+
+    *state = ResponseState::Deferred;
+    Ok(())
+}
+
+pub(crate) async fn defer_update(responder: &Responder<'_>) -> Result<(), twilight_http::Error> {
+    let mut state = responder.state.lock().await;
+    if *state != ResponseState::Pending {
+        return Ok(());
+    }
+
+    responder.interaction_client()
+        .create_response(
+            responder.interaction_id,
+            &responder.token,
+            &InteractionResponse {
+                kind: InteractionResponseType::DeferredUpdateMessage,
+                data: None,
+            },
+        )
+        .await?;
+
+    *state = ResponseState::Deferred;
+    Ok(())
+}
+
+pub(crate) async fn delete(responder: &Responder<'_>, target: ReplyTarget) -> Result<(), ReplyError> {
+    if responder.is_expired() {
+        return Err(ReplyError::TokenExpired);
+    }
+    let client = responder.interaction_client();
+    match target {
+        ReplyTarget::Original => {
+            if *responder.state.lock().await == ResponseState::Pending {
+                return Err(ReplyError::NotResponded);
+            }
+
+            client.delete_response(&responder.token).await?;
+        },
+        ReplyTarget::Followup(id) => {
+            client.delete_followup(&responder.token, id).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Write};
+use std::sync::Arc;
+
+/// The locale used when no translation is found in the user nor the guild locales, unless
+/// another one is set using [`FrameworkBuilder::default_locale`].
+///
+/// [`FrameworkBuilder::default_locale`]: crate::builder::FrameworkBuilder::default_locale
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// A source of translated messages, used by [`SlashContext::t`] and by the messages the
+/// framework produces itself, like the errors returned when an argument fails to parse.
+///
+/// Translations are templates which can reference the arguments given to them between braces,
+/// like `Banned {user}`.
+///
+/// The messages produced by the framework use the following keys:
+///
+/// - `vesper.constraint.min_value` and `vesper.constraint.max_value`, receiving `{limit}`.
+/// - `vesper.constraint.min_length` and `vesper.constraint.max_length`, receiving `{limit}`.
+/// - `vesper.constraint.channel_types`.
+/// - `vesper.parse.choice`, used when the value is not one of the dynamic choices of the argument.
+/// - `vesper.parse.string`, `vesper.parse.integer`, `vesper.parse.number`,
+///   `vesper.parse.boolean`, `vesper.parse.attachment`, `vesper.parse.channel`,
+///   `vesper.parse.user`, `vesper.parse.member`, `vesper.parse.role` and
+///   `vesper.parse.mentionable`, used when the received value has the wrong type.
+/// - `vesper.parse.out_of_range`, used by integer types and ranges.
+/// - `vesper.parse.length`, receiving `{min}` and `{max}`.
+/// - `vesper.parse.color`, `vesper.parse.emoji`, `vesper.parse.message_link`,
+///   `vesper.parse.message_link_channel`, `vesper.parse.snowflake`, `vesper.parse.url`,
+///   `vesper.parse.duration`, `vesper.parse.datetime` and `vesper.parse.invalid_pattern`.
+/// - The [description](crate::parsers::RegexSource::DESCRIPTION) of a pattern.
+/// - `vesper.parse.option`, used when the value is not one of the variants of a derived enum.
+/// - `vesper.parse.missing`, receiving `{name}`, used when a required argument is not received.
+/// - `vesper.parse.too_many_arguments`, used when more arguments than declared are received.
+/// - `vesper.context.author`, `vesper.context.guild` and `vesper.context.locale`, used when an
+///   [injected](crate::inject::FromContext) value is not available.
+/// - `vesper.modal.retry`, the label of the button used to fix an invalid modal.
+/// - `vesper.modal.unknown_field` and `vesper.modal.missing_field`, receiving `{field}`, and
+///   `vesper.modal.invalid_field`, receiving `{field}` and `{error}`, used when a submitted modal
+///   fails to parse.
+/// - `vesper.modal.not_a_modal`, used when the interaction is not a modal submission.
+/// - `vesper.modal.submitted`, replacing the error message once the modal is fixed.
+///
+/// [`SlashContext::t`]: crate::context::SlashContext::t
+pub trait Translator: Send + Sync {
+    /// Gets the template of the given key in the given locale, returning `None` if there is no
+    /// translation for it.
+    fn translate(&self, locale: &str, key: &str) -> Option<String>;
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&str, &str) -> Option<String> + Send + Sync
+{
+    fn translate(&self, locale: &str, key: &str) -> Option<String> {
+        (self)(locale, key)
+    }
+}
+
+#[cfg(feature = "localizations")]
+impl Translator for crate::localizations::Catalog {
+    fn translate(&self, locale: &str, key: &str) -> Option<String> {
+        self.get(locale, key).map(ToString::to_string)
+    }
+}
+
+/// The translator of the framework, along with the locale used when no other one has a
+/// translation.
+pub(crate) struct Translations {
+    pub(crate) translator: Option<Arc<dyn Translator>>,
+    pub(crate) default_locale: String,
+}
+
+impl Translations {
+    /// Translates the given key into the first of the given locales having a translation,
+    /// falling back to the default locale.
+    pub(crate) fn translate(
+        &self,
+        locales: [Option<&str>; 2],
+        key: &str,
+        args: &[(&str, &dyn Display)]
+    ) -> Option<String> {
+        let translator = self.translator.as_ref()?;
+        let template = locales.iter()
+            .flatten()
+            .copied()
+            .chain(std::iter::once(self.default_locale.as_str()))
+            .find_map(|locale| translator.translate(locale, key))?;
+
+        Some(format(&template, args))
+    }
+}
+
+/// Replaces the arguments referenced in the given template with their values, leaving unknown
+/// ones untouched.
+fn format(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            args.iter().find(|(key, _)| *key == name).map(|(_, value)| (end, value))
+        });
+
+        match value {
+            Some((end, value)) => {
+                let _ = write!(output, "{}", value);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                output.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}